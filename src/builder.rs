@@ -41,7 +41,9 @@ use crate::context::AsContextRef;
 #[llvm_versions(7..)]
 use crate::debug_info::DILocation;
 use crate::support::to_c_str;
-use crate::types::{AsTypeRef, BasicType, FloatMathType, FunctionType, IntMathType, PointerMathType, PointerType};
+use crate::types::{
+    AsTypeRef, BasicType, BasicTypeEnum, FloatMathType, FunctionType, IntMathType, PointerMathType, PointerType,
+};
 #[llvm_versions(..=14)]
 use crate::values::CallableValue;
 use crate::values::{
@@ -154,6 +156,9 @@ impl<'ctx> Builder<'ctx> {
     /// Builds a function return instruction for a return type which is an aggregate type (ie structs and arrays).
     /// It is not necessary to use this over `build_return` but may be more convenient to use.
     ///
+    /// Returns an `Err(BuilderError::ValueTypeMismatch)` if the current function does not return an aggregate,
+    /// or if the number of values does not match the number of fields or elements of its return type.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -181,6 +186,27 @@ impl<'ctx> Builder<'ctx> {
         if self.positioned.get() != PositionState::Set {
             return Err(BuilderError::UnsetPosition);
         }
+
+        let return_type = self
+            .get_insert_block()
+            .and_then(|block| block.get_parent())
+            .and_then(|function| function.get_type().get_return_type());
+        let len = match return_type {
+            Some(BasicTypeEnum::StructType(struct_type)) => struct_type.count_fields(),
+            Some(BasicTypeEnum::ArrayType(array_type)) => array_type.len(),
+            _ => {
+                return Err(BuilderError::ValueTypeMismatch(
+                    "build_aggregate_return requires a function returning a struct or array.",
+                ))
+            },
+        };
+
+        if len as usize != values.len() {
+            return Err(BuilderError::ValueTypeMismatch(
+                "The number of values passed to build_aggregate_return does not match the return type.",
+            ));
+        }
+
         let mut args: Vec<LLVMValueRef> = values.iter().map(|val| val.as_value_ref()).collect();
         let value = unsafe { LLVMBuildAggregateRet(self.builder, args.as_mut_ptr(), args.len() as u32) };
 
@@ -1697,6 +1723,7 @@ impl<'ctx> Builder<'ctx> {
         }
     }
 
+    /// Gets the basic block the builder is currently positioned in, if any.
     pub fn get_insert_block(&self) -> Option<BasicBlock<'ctx>> {
         unsafe { BasicBlock::new(LLVMGetInsertBlock(self.builder)) }
     }
@@ -3116,7 +3143,12 @@ impl<'ctx> Builder<'ctx> {
         unsafe { Ok(<<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(value)) }
     }
 
+    /// Clears the position of the builder, so that it is no longer inside any basic block.
+    ///
+    /// Afterwards, all `build_*` methods will return `Err(BuilderError::UnsetPosition)` until one of the `position_*` methods is called again.
     pub fn clear_insertion_position(&self) {
+        self.positioned.set(PositionState::NotSet);
+
         unsafe { LLVMClearInsertionPosition(self.builder) }
     }
 
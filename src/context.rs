@@ -409,6 +409,51 @@ impl PartialEq<ContextRef<'_>> for Context {
 ///
 /// A `Context` is not thread safe and cannot be shared across threads. Multiple `Context`s
 /// can, however, execute on different threads simultaneously according to the LLVM docs.
+///
+/// # Threading
+///
+/// `Context` is `Send` but not `Sync`: it may be moved to another thread as a whole, but a
+/// reference to it may not be shared between threads. Everything created from a `Context`
+/// (`Module`s, `Builder`s, types and values) borrows it and is neither `Send` nor `Sync`, so
+/// all work for one `Context` happens on the thread which currently owns it. To compile in
+/// parallel, give each thread its own `Context`.
+///
+/// ```no_run
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+///
+/// std::thread::spawn(move || {
+///     let module = context.create_module("my_module");
+///
+///     module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+/// }).join().unwrap();
+/// ```
+///
+/// Sharing a `Context` between threads is rejected at compile time:
+///
+/// ```compile_fail,E0277
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+///
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| context.create_module("my_module"));
+/// });
+/// ```
+///
+/// As is sending something which borrows a `Context`:
+///
+/// ```compile_fail,E0277
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+/// let module = context.create_module("my_module");
+///
+/// std::thread::scope(|scope| {
+///     scope.spawn(move || module.get_name().to_owned());
+/// });
+/// ```
 #[derive(Debug, PartialEq, Eq)]
 pub struct Context {
     pub(crate) context: ContextImpl,
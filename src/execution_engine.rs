@@ -79,6 +79,44 @@ impl Display for RemoveModuleError {
     }
 }
 
+/// An error which may occur when creating an `ExecutionEngine` from a `Module`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExecutionEngineError {
+    /// The native target could not be initialized.
+    TargetInit,
+    /// The native asm printer could not be initialized.
+    AsmPrinterInit,
+    /// The native asm parser could not be initialized.
+    AsmParserInit,
+    /// The native disassembler could not be initialized.
+    DisassemblerInit,
+    /// The `Module` is already owned by an `ExecutionEngine`.
+    ModuleAlreadyOwned,
+    /// LLVM failed to create the `ExecutionEngine`, containing its error message.
+    EngineCreation(LLVMString),
+}
+
+impl Error for ExecutionEngineError {}
+
+impl ExecutionEngineError {
+    fn as_str(&self) -> &str {
+        match self {
+            ExecutionEngineError::TargetInit => "Unable to initialize the native target",
+            ExecutionEngineError::AsmPrinterInit => "Unable to initialize the native asm printer",
+            ExecutionEngineError::AsmParserInit => "Unable to initialize the native asm parser",
+            ExecutionEngineError::DisassemblerInit => "Unable to initialize the native disassembler",
+            ExecutionEngineError::ModuleAlreadyOwned => "This module is already owned by an ExecutionEngine",
+            ExecutionEngineError::EngineCreation(string) => string.to_str().unwrap_or("LLVMError with invalid unicode"),
+        }
+    }
+}
+
+impl Display for ExecutionEngineError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "ExecutionEngineError({})", self.as_str())
+    }
+}
+
 /// A reference-counted wrapper around LLVM's execution engine.
 ///
 /// # Note
@@ -199,17 +237,21 @@ impl<'ctx> ExecutionEngine<'ctx> {
     /// assert!(ee.add_module(&module).is_err());
     /// ```
     pub fn add_module(&self, module: &Module<'ctx>) -> Result<(), ()> {
-        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
-
+        // A module may only ever be owned by a single ExecutionEngine, or it would be disposed of twice
         if module.owned_by_ee.borrow().is_some() {
             return Err(());
         }
 
+        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
+
         *module.owned_by_ee.borrow_mut() = Some(self.clone());
 
         Ok(())
     }
 
+    /// Removes a module from an `ExecutionEngine`, handing ownership of it back to the `Module`.
+    ///
+    /// The method will be `Err` if the module is not owned by this `ExecutionEngine`.
     pub fn remove_module(&self, module: &Module<'ctx>) -> Result<(), RemoveModuleError> {
         match *module.owned_by_ee.borrow() {
             Some(ref ee) if ee.execution_engine_inner() != self.execution_engine_inner() => {
@@ -351,6 +393,7 @@ impl<'ctx> ExecutionEngine<'ctx> {
         Ok(address as usize)
     }
 
+    /// Gets the `TargetData` this `ExecutionEngine` lays out types with, such as the size of a pointer.
     // REVIEW: Not sure if an EE's target data can change.. if so we might want to update the value
     // when making this call
     pub fn get_target_data(&self) -> &TargetData {
@@ -15,6 +15,8 @@ use std::path::Path;
 use std::ptr;
 use std::slice;
 
+/// An owned, read-only block of memory managed by LLVM, such as the contents of a file
+/// or serialized bitcode.
 #[derive(Debug)]
 pub struct MemoryBuffer {
     pub(crate) memory_buffer: LLVMMemoryBufferRef,
@@ -31,6 +33,7 @@ impl MemoryBuffer {
         self.memory_buffer
     }
 
+    /// Creates a new `MemoryBuffer` holding the contents of the file at the given path.
     pub fn create_from_file(path: &Path) -> Result<Self, LLVMString> {
         let path = to_c_str(path.to_str().expect("Did not find a valid Unicode path string"));
         let mut memory_buffer = ptr::null_mut();
@@ -29,7 +29,7 @@ use llvm_sys::LLVMLinkage;
 use llvm_sys::LLVMModuleFlagBehavior;
 
 use std::cell::{Cell, Ref, RefCell};
-use std::ffi::CStr;
+use std::ffi::{CStr, NulError};
 use std::fs::File;
 use std::marker::PhantomData;
 use std::mem::{forget, MaybeUninit};
@@ -43,11 +43,11 @@ use crate::context::{AsContextRef, Context, ContextRef};
 use crate::data_layout::DataLayout;
 #[llvm_versions(7..)]
 use crate::debug_info::{DICompileUnit, DWARFEmissionKind, DWARFSourceLanguage, DebugInfoBuilder};
-use crate::execution_engine::ExecutionEngine;
+use crate::execution_engine::{ExecutionEngine, ExecutionEngineError};
 use crate::memory_buffer::MemoryBuffer;
 #[llvm_versions(13..)]
 use crate::passes::PassBuilderOptions;
-use crate::support::{to_c_str, LLVMString};
+use crate::support::{to_c_str, to_checked_c_str, LLVMString};
 #[llvm_versions(13..)]
 use crate::targets::TargetMachine;
 use crate::targets::{InitializationConfig, Target, TargetTriple};
@@ -216,9 +216,38 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(fn_val.get_linkage(), Linkage::External);
     /// ```
     pub fn add_function(&self, name: &str, ty: FunctionType<'ctx>, linkage: Option<Linkage>) -> FunctionValue<'ctx> {
-        let c_string = to_c_str(name);
+        self.add_function_c_str(&to_c_str(name), ty, linkage)
+    }
+
+    /// Like `add_function`, but returns an error rather than truncating a `name`
+    /// which contains a null byte.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.f32_type().fn_type(&[], false);
+    ///
+    /// assert!(module.try_add_function("my\0function", fn_type, None).is_err());
+    ///
+    /// let fn_val = module.try_add_function("my_function", fn_type, None).unwrap();
+    ///
+    /// assert_eq!(fn_val.get_name().to_str(), Ok("my_function"));
+    /// ```
+    pub fn try_add_function(
+        &self,
+        name: &str,
+        ty: FunctionType<'ctx>,
+        linkage: Option<Linkage>,
+    ) -> Result<FunctionValue<'ctx>, NulError> {
+        Ok(self.add_function_c_str(&to_checked_c_str(name)?, ty, linkage))
+    }
+
+    fn add_function_c_str(&self, name: &CStr, ty: FunctionType<'ctx>, linkage: Option<Linkage>) -> FunctionValue<'ctx> {
         let fn_value = unsafe {
-            FunctionValue::new(LLVMAddFunction(self.module.get(), c_string.as_ptr(), ty.as_type_ref()))
+            FunctionValue::new(LLVMAddFunction(self.module.get(), name.as_ptr(), ty.as_type_ref()))
                 .expect("add_function should always succeed in adding a new function")
         };
 
@@ -308,9 +337,30 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(fn_value, module.get_function("my_fn").unwrap());
     /// ```
     pub fn get_function(&self, name: &str) -> Option<FunctionValue<'ctx>> {
-        let c_string = to_c_str(name);
+        self.get_function_c_str(&to_c_str(name))
+    }
+
+    /// Like `get_function`, but returns an error rather than truncating a `name`
+    /// which contains a null byte.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// assert_eq!(module.try_get_function("my_fn"), Ok(Some(fn_value)));
+    /// assert!(module.try_get_function("my_fn\0other").is_err());
+    /// ```
+    pub fn try_get_function(&self, name: &str) -> Result<Option<FunctionValue<'ctx>>, NulError> {
+        Ok(self.get_function_c_str(&to_checked_c_str(name)?))
+    }
 
-        unsafe { FunctionValue::new(LLVMGetNamedFunction(self.module.get(), c_string.as_ptr())) }
+    fn get_function_c_str(&self, name: &CStr) -> Option<FunctionValue<'ctx>> {
+        unsafe { FunctionValue::new(LLVMGetNamedFunction(self.module.get(), name.as_ptr())) }
     }
 
     /// An iterator over the functions in this `Module`.
@@ -434,12 +484,49 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_triple(), triple);
     /// ```
     pub fn get_triple(&self) -> TargetTriple {
-        // REVIEW: This isn't an owned LLVMString, is it? If so, need to deallocate.
+        // LLVMGetTarget returns a string owned by the module, so it is copied rather than deallocated
         let target_str = unsafe { LLVMGetTarget(self.module.get()) };
 
         unsafe { TargetTriple::new(LLVMString::create_from_c_str(CStr::from_ptr(target_str))) }
     }
 
+    // Initializes the native target components an `ExecutionEngine` depends on, and ensures this
+    // `Module` hasn't already been handed over to another `ExecutionEngine`.
+    fn prepare_execution_engine(&self) -> Result<(), ExecutionEngineError> {
+        if self.owned_by_ee.borrow().is_some() {
+            return Err(ExecutionEngineError::ModuleAlreadyOwned);
+        }
+
+        let config = InitializationConfig {
+            asm_parser: false,
+            asm_printer: false,
+            base: false,
+            disassembler: false,
+            info: false,
+            machine_code: false,
+        };
+
+        Target::initialize_native(&InitializationConfig { base: true, ..config })
+            .map_err(|_| ExecutionEngineError::TargetInit)?;
+        Target::initialize_native(&InitializationConfig {
+            asm_printer: true,
+            ..config
+        })
+        .map_err(|_| ExecutionEngineError::AsmPrinterInit)?;
+        Target::initialize_native(&InitializationConfig {
+            asm_parser: true,
+            ..config
+        })
+        .map_err(|_| ExecutionEngineError::AsmParserInit)?;
+        Target::initialize_native(&InitializationConfig {
+            disassembler: true,
+            ..config
+        })
+        .map_err(|_| ExecutionEngineError::DisassemblerInit)?;
+
+        Ok(())
+    }
+
     /// Creates an `ExecutionEngine` from this `Module`.
     ///
     /// # Example
@@ -457,17 +544,8 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_context(), context);
     /// ```
     // SubType: ExecutionEngine<Basic?>
-    pub fn create_execution_engine(&self) -> Result<ExecutionEngine<'ctx>, LLVMString> {
-        Target::initialize_native(&InitializationConfig::default()).map_err(|mut err_string| {
-            err_string.push('\0');
-
-            LLVMString::create_from_str(&err_string)
-        })?;
-
-        if self.owned_by_ee.borrow().is_some() {
-            let string = "This module is already owned by an ExecutionEngine.\0";
-            return Err(LLVMString::create_from_str(string));
-        }
+    pub fn create_execution_engine(&self) -> Result<ExecutionEngine<'ctx>, ExecutionEngineError> {
+        self.prepare_execution_engine()?;
 
         let mut execution_engine = MaybeUninit::uninit();
         let mut err_string = MaybeUninit::uninit();
@@ -482,7 +560,9 @@ impl<'ctx> Module<'ctx> {
 
         if code == 1 {
             unsafe {
-                return Err(LLVMString::new(err_string.assume_init()));
+                return Err(ExecutionEngineError::EngineCreation(LLVMString::new(
+                    err_string.assume_init(),
+                )));
             }
         }
 
@@ -511,17 +591,8 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_context(), context);
     /// ```
     // SubType: ExecutionEngine<Interpreter>
-    pub fn create_interpreter_execution_engine(&self) -> Result<ExecutionEngine<'ctx>, LLVMString> {
-        Target::initialize_native(&InitializationConfig::default()).map_err(|mut err_string| {
-            err_string.push('\0');
-
-            LLVMString::create_from_str(&err_string)
-        })?;
-
-        if self.owned_by_ee.borrow().is_some() {
-            let string = "This module is already owned by an ExecutionEngine.\0";
-            return Err(LLVMString::create_from_str(string));
-        }
+    pub fn create_interpreter_execution_engine(&self) -> Result<ExecutionEngine<'ctx>, ExecutionEngineError> {
+        self.prepare_execution_engine()?;
 
         let mut execution_engine = MaybeUninit::uninit();
         let mut err_string = MaybeUninit::uninit();
@@ -537,7 +608,9 @@ impl<'ctx> Module<'ctx> {
 
         if code == 1 {
             unsafe {
-                return Err(LLVMString::new(err_string.assume_init()));
+                return Err(ExecutionEngineError::EngineCreation(LLVMString::new(
+                    err_string.assume_init(),
+                )));
             }
         }
 
@@ -570,17 +643,8 @@ impl<'ctx> Module<'ctx> {
     pub fn create_jit_execution_engine(
         &self,
         opt_level: OptimizationLevel,
-    ) -> Result<ExecutionEngine<'ctx>, LLVMString> {
-        Target::initialize_native(&InitializationConfig::default()).map_err(|mut err_string| {
-            err_string.push('\0');
-
-            LLVMString::create_from_str(&err_string)
-        })?;
-
-        if self.owned_by_ee.borrow().is_some() {
-            let string = "This module is already owned by an ExecutionEngine.\0";
-            return Err(LLVMString::create_from_str(string));
-        }
+    ) -> Result<ExecutionEngine<'ctx>, ExecutionEngineError> {
+        self.prepare_execution_engine()?;
 
         let mut execution_engine = MaybeUninit::uninit();
         let mut err_string = MaybeUninit::uninit();
@@ -597,7 +661,9 @@ impl<'ctx> Module<'ctx> {
 
         if code == 1 {
             unsafe {
-                return Err(LLVMString::new(err_string.assume_init()));
+                return Err(ExecutionEngineError::EngineCreation(LLVMString::new(
+                    err_string.assume_init(),
+                )));
             }
         }
 
@@ -611,6 +677,11 @@ impl<'ctx> Module<'ctx> {
 
     /// Creates a `GlobalValue` based on a type in an address space.
     ///
+    /// The global has external linkage and no initializer, so it starts out as a declaration of
+    /// a global defined elsewhere (printed as `external global`), such as one provided by a
+    /// runtime library. Giving it an initializer with `GlobalValue::set_initializer` turns it
+    /// into a definition.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -631,17 +702,48 @@ impl<'ctx> Module<'ctx> {
         address_space: Option<AddressSpace>,
         name: &str,
     ) -> GlobalValue<'ctx> {
-        let c_string = to_c_str(name);
+        self.add_global_c_str(type_, address_space, &to_c_str(name))
+    }
+
+    /// Like `add_global`, but returns an error rather than truncating a `name`
+    /// which contains a null byte.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let i8_type = context.i8_type();
+    ///
+    /// assert!(module.try_add_global(i8_type, None, "my\0global").is_err());
+    ///
+    /// let global = module.try_add_global(i8_type, None, "my_global").unwrap();
+    ///
+    /// assert_eq!(module.get_first_global().unwrap(), global);
+    /// ```
+    pub fn try_add_global<T: BasicType<'ctx>>(
+        &self,
+        type_: T,
+        address_space: Option<AddressSpace>,
+        name: &str,
+    ) -> Result<GlobalValue<'ctx>, NulError> {
+        Ok(self.add_global_c_str(type_, address_space, &to_checked_c_str(name)?))
+    }
 
+    fn add_global_c_str<T: BasicType<'ctx>>(
+        &self,
+        type_: T,
+        address_space: Option<AddressSpace>,
+        name: &CStr,
+    ) -> GlobalValue<'ctx> {
         let value = unsafe {
             match address_space {
-                Some(address_space) => LLVMAddGlobalInAddressSpace(
-                    self.module.get(),
-                    type_.as_type_ref(),
-                    c_string.as_ptr(),
-                    address_space.0,
-                ),
-                None => LLVMAddGlobal(self.module.get(), type_.as_type_ref(), c_string.as_ptr()),
+                Some(address_space) => {
+                    LLVMAddGlobalInAddressSpace(self.module.get(), type_.as_type_ref(), name.as_ptr(), address_space.0)
+                },
+                None => LLVMAddGlobal(self.module.get(), type_.as_type_ref(), name.as_ptr()),
             }
         };
 
@@ -872,6 +974,32 @@ impl<'ctx> Module<'ctx> {
         }
     }
 
+    /// Gets the inline assembly of the `Module`, which is empty unless it has been set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    ///
+    /// assert_eq!(module.get_inline_assembly().to_str(), Ok(""));
+    ///
+    /// module.set_inline_assembly(".globl my_stub");
+    ///
+    /// assert_eq!(module.get_inline_assembly().to_str(), Ok(".globl my_stub\n"));
+    /// ```
+    #[llvm_versions(7..)]
+    pub fn get_inline_assembly(&self) -> &CStr {
+        use llvm_sys::core::LLVMGetModuleInlineAsm;
+
+        let mut len = 0;
+        let ptr = unsafe { LLVMGetModuleInlineAsm(self.module.get(), &mut len) };
+
+        unsafe { CStr::from_ptr(ptr) }
+    }
+
     // REVIEW: Should module take ownership of metadata?
     // REVIEW: Should we return a MetadataValue for the global since it's its own value?
     // it would be the last item in get_global_metadata I believe
@@ -1096,8 +1224,30 @@ impl<'ctx> Module<'ctx> {
     /// assert_eq!(module.get_global("my_global").unwrap(), global);
     /// ```
     pub fn get_global(&self, name: &str) -> Option<GlobalValue<'ctx>> {
-        let c_string = to_c_str(name);
-        let value = unsafe { LLVMGetNamedGlobal(self.module.get(), c_string.as_ptr()) };
+        self.get_global_c_str(&to_c_str(name))
+    }
+
+    /// Like `get_global`, but returns an error rather than truncating a `name`
+    /// which contains a null byte.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let global = module.add_global(context.i8_type(), None, "my_global");
+    ///
+    /// assert_eq!(module.try_get_global("my_global"), Ok(Some(global)));
+    /// assert!(module.try_get_global("my_global\0other").is_err());
+    /// ```
+    pub fn try_get_global(&self, name: &str) -> Result<Option<GlobalValue<'ctx>>, NulError> {
+        Ok(self.get_global_c_str(&to_checked_c_str(name)?))
+    }
+
+    fn get_global_c_str(&self, name: &CStr) -> Option<GlobalValue<'ctx>> {
+        let value = unsafe { LLVMGetNamedGlobal(self.module.get(), name.as_ptr()) };
 
         if value.is_null() {
             return None;
@@ -1195,7 +1345,7 @@ impl<'ctx> Module<'ctx> {
     /// let context = Context::create();
     /// let module = context.create_module("my_module");
     ///
-    /// assert_eq!(module.get_name().to_str(), Ok("my_mdoule"));
+    /// assert_eq!(module.get_name().to_str(), Ok("my_module"));
     /// ```
     pub fn get_name(&self) -> &CStr {
         let mut length = 0;
@@ -1289,8 +1439,14 @@ impl<'ctx> Module<'ctx> {
     /// let context = Context::create();
     /// let module = context.create_module("mod");
     /// let module2 = context.create_module("mod2");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    ///
+    /// module.add_function("f", fn_type, None);
+    /// module2.add_function("f2", fn_type, None);
     ///
     /// assert!(module.link_in_module(module2).is_ok());
+    /// assert!(module.get_function("f").is_some());
+    /// assert!(module.get_function("f2").is_some());
     /// ```
     pub fn link_in_module(&self, other: Self) -> Result<(), LLVMString> {
         if other.owned_by_ee.borrow().is_some() {
@@ -274,11 +274,18 @@ impl PassManager<FunctionValue<'_>> {
         self.pass_manager
     }
 
-    // return true means some pass modified the module, not an error occurred
+    /// Runs the initialization routines of all function passes. This must be called
+    /// before `run_on` is used on any function.
+    ///
+    /// Returns true if any pass modified the module, which does not indicate an error.
     pub fn initialize(&self) -> bool {
         unsafe { LLVMInitializeFunctionPassManager(self.pass_manager) == 1 }
     }
 
+    /// Runs the finalization routines of all function passes. This should be called
+    /// once all functions have been run through `run_on`.
+    ///
+    /// Returns true if any pass modified the module, which does not indicate an error.
     pub fn finalize(&self) -> bool {
         unsafe { LLVMFinalizeFunctionPassManager(self.pass_manager) == 1 }
     }
@@ -10,7 +10,7 @@ use llvm_sys::support::{LLVMLoadLibraryPermanently, LLVMSearchForAddressOfSymbol
 
 use std::borrow::Cow;
 use std::error::Error;
-use std::ffi::{CStr, CString};
+use std::ffi::{CStr, CString, NulError};
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::Deref;
 use std::path::Path;
@@ -214,6 +214,9 @@ pub fn enable_llvm_pretty_stack_trace() {
 /// A) Finds a terminating null byte in the Rust string and can reference it directly like a C string.
 ///
 /// B) Finds no null byte and allocates a new C string based on the input Rust string.
+///
+/// A string with an interior null byte is seen by LLVM as truncated at the first null byte.
+/// Use `to_checked_c_str` where that should be reported as an error instead.
 pub(crate) fn to_c_str(mut s: &str) -> Cow<'_, CStr> {
     if s.is_empty() {
         s = "\0";
@@ -227,8 +230,27 @@ pub(crate) fn to_c_str(mut s: &str) -> Cow<'_, CStr> {
     unsafe { Cow::from(CStr::from_ptr(s.as_ptr() as *const _)) }
 }
 
+/// Like `to_c_str`, but fails on a null byte anywhere other than at the very end of the string
+/// rather than letting LLVM silently truncate it.
+pub(crate) fn to_checked_c_str(s: &str) -> Result<Cow<'_, CStr>, NulError> {
+    match s.strip_suffix('\0') {
+        Some(stripped) if !stripped.contains('\0') => Ok(to_c_str(s)),
+        _ => Ok(Cow::from(CString::new(s)?)),
+    }
+}
+
 #[test]
 fn test_to_c_str() {
     assert!(matches!(to_c_str("my string"), Cow::Owned(_)));
     assert!(matches!(to_c_str("my string\0"), Cow::Borrowed(_)));
+    assert_eq!(to_c_str("my\0string").to_str(), Ok("my"));
+}
+
+#[test]
+fn test_to_checked_c_str() {
+    assert!(matches!(to_checked_c_str("my string"), Ok(Cow::Owned(_))));
+    assert!(matches!(to_checked_c_str("my string\0"), Ok(Cow::Borrowed(_))));
+    assert_eq!(to_checked_c_str("").unwrap().to_str(), Ok(""));
+    assert_eq!(to_checked_c_str("my\0string").unwrap_err().nul_position(), 2);
+    assert_eq!(to_checked_c_str("my\0string\0").unwrap_err().nul_position(), 2);
 }
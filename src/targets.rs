@@ -29,13 +29,13 @@ use crate::data_layout::DataLayout;
 use crate::memory_buffer::MemoryBuffer;
 use crate::module::Module;
 use crate::passes::PassManager;
-use crate::support::{to_c_str, LLVMString};
+use crate::support::{to_c_str, to_checked_c_str, LLVMString};
 use crate::types::{AnyType, AsTypeRef, IntType, StructType};
 use crate::values::{AsValueRef, GlobalValue};
 use crate::{AddressSpace, OptimizationLevel};
 
 use std::default::Default;
-use std::ffi::CStr;
+use std::ffi::{CStr, NulError};
 use std::fmt;
 use std::mem::MaybeUninit;
 use std::path::Path;
@@ -142,6 +142,16 @@ impl TargetTriple {
         }
     }
 
+    /// Like `create`, but returns an error rather than truncating a `triple`
+    /// which contains a null byte.
+    pub fn try_create(triple: &str) -> Result<TargetTriple, NulError> {
+        let c_string = to_checked_c_str(triple)?;
+
+        Ok(TargetTriple {
+            triple: LLVMString::create_from_c_str(&c_string),
+        })
+    }
+
     pub fn as_str(&self) -> &CStr {
         unsafe { CStr::from_ptr(self.as_ptr()) }
     }
@@ -614,6 +614,20 @@ impl<'ctx> TryFrom<BasicMetadataTypeEnum<'ctx>> for BasicTypeEnum<'ctx> {
     }
 }
 
+impl<'ctx> From<BasicTypeEnum<'ctx>> for AnyTypeEnum<'ctx> {
+    fn from(value: BasicTypeEnum<'ctx>) -> Self {
+        use BasicTypeEnum::*;
+        match value {
+            ArrayType(at) => at.into(),
+            FloatType(ft) => ft.into(),
+            IntType(it) => it.into(),
+            PointerType(pt) => pt.into(),
+            StructType(st) => st.into(),
+            VectorType(vt) => vt.into(),
+        }
+    }
+}
+
 impl<'ctx> From<BasicTypeEnum<'ctx>> for BasicMetadataTypeEnum<'ctx> {
     fn from(value: BasicTypeEnum<'ctx>) -> Self {
         use BasicTypeEnum::*;
@@ -1,6 +1,7 @@
-use llvm_sys::core::{LLVMConstReal, LLVMConstRealOfStringAndSize};
+use llvm_sys::core::{LLVMConstReal, LLVMConstRealOfStringAndSize, LLVMGetTypeKind};
 use llvm_sys::execution_engine::LLVMCreateGenericValueOfFloat;
 use llvm_sys::prelude::LLVMTypeRef;
+use llvm_sys::LLVMTypeKind;
 
 use crate::context::ContextRef;
 use crate::support::LLVMString;
@@ -12,6 +13,25 @@ use crate::AddressSpace;
 
 use std::fmt::{self, Display};
 
+/// The floating point format of a `FloatType`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FloatTypeKind {
+    /// 16-bit IEEE half precision, `half`.
+    Half,
+    /// 16-bit brain floating point, `bfloat`. Only available in LLVM 11 and later.
+    BFloat,
+    /// 32-bit IEEE single precision, `float`.
+    Float,
+    /// 64-bit IEEE double precision, `double`.
+    Double,
+    /// 80-bit x87 extended precision, `x86_fp80`.
+    X86FP80,
+    /// 128-bit IEEE quad precision, `fp128`.
+    FP128,
+    /// 128-bit PowerPC double-double, `ppc_fp128`.
+    PPCFP128,
+}
+
 /// A `FloatType` is the type of a floating point constant or variable.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct FloatType<'ctx> {
@@ -157,6 +177,42 @@ impl<'ctx> FloatType<'ctx> {
         unsafe { FloatValue::new(self.float_type.const_zero()) }
     }
 
+    /// Gets the floating point format of this `FloatType`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::FloatTypeKind;
+    ///
+    /// let context = Context::create();
+    ///
+    /// assert_eq!(context.f32_type().get_kind(), FloatTypeKind::Float);
+    /// assert_eq!(context.f64_type().get_kind(), FloatTypeKind::Double);
+    /// ```
+    pub fn get_kind(self) -> FloatTypeKind {
+        match unsafe { LLVMGetTypeKind(self.as_type_ref()) } {
+            LLVMTypeKind::LLVMHalfTypeKind => FloatTypeKind::Half,
+            #[cfg(any(
+                feature = "llvm11-0",
+                feature = "llvm12-0",
+                feature = "llvm13-0",
+                feature = "llvm14-0",
+                feature = "llvm15-0",
+                feature = "llvm16-0",
+                feature = "llvm17-0",
+                feature = "llvm18-0"
+            ))]
+            LLVMTypeKind::LLVMBFloatTypeKind => FloatTypeKind::BFloat,
+            LLVMTypeKind::LLVMFloatTypeKind => FloatTypeKind::Float,
+            LLVMTypeKind::LLVMDoubleTypeKind => FloatTypeKind::Double,
+            LLVMTypeKind::LLVMX86_FP80TypeKind => FloatTypeKind::X86FP80,
+            LLVMTypeKind::LLVMFP128TypeKind => FloatTypeKind::FP128,
+            LLVMTypeKind::LLVMPPC_FP128TypeKind => FloatTypeKind::PPCFP128,
+            _ => unreachable!("FloatType has a non floating point type kind."),
+        }
+    }
+
     /// Gets the size of this `FloatType`. Value may vary depending on the target architecture.
     ///
     /// # Example
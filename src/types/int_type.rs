@@ -82,6 +82,9 @@ impl<'ctx> IntType<'ctx> {
 
     /// Creates an `IntValue` representing a constant value of this `IntType`. It will be automatically assigned this `IntType`'s `Context`.
     ///
+    /// The value is truncated to the bit width of this `IntType`. When the type is wider than 64 bits,
+    /// `sign_extend` determines whether the upper bits are filled with the sign bit of `value` or with zeros.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -90,8 +93,8 @@ impl<'ctx> IntType<'ctx> {
     /// let context = Context::create();
     /// let i32_type = context.i32_type();
     /// let i32_value = i32_type.const_int(42, false);
+    /// let i32_minus_one = i32_type.const_int(-1i64 as u64, true);
     /// ```
-    // TODOC: Maybe better explain sign extension
     pub fn const_int(self, value: u64, sign_extend: bool) -> IntValue<'ctx> {
         unsafe { IntValue::new(LLVMConstInt(self.as_type_ref(), value, sign_extend as i32)) }
     }
@@ -1,4 +1,9 @@
 //! A type is a classification which determines how data is used.
+//!
+//! LLVM uniques types within a `Context`, so two type handles compare equal
+//! exactly when they describe the same type. Comparing types which belong to
+//! different contexts is meaningless: they are never equal, even when they
+//! have the same structure.
 
 #[deny(missing_docs)]
 mod array_type;
@@ -24,7 +29,7 @@ mod void_type;
 
 pub use crate::types::array_type::ArrayType;
 pub use crate::types::enums::{AnyTypeEnum, BasicMetadataTypeEnum, BasicTypeEnum};
-pub use crate::types::float_type::FloatType;
+pub use crate::types::float_type::{FloatType, FloatTypeKind};
 pub use crate::types::fn_type::FunctionType;
 pub use crate::types::int_type::{IntType, StringRadix};
 pub use crate::types::metadata_type::MetadataType;
@@ -57,8 +62,8 @@ use crate::support::LLVMString;
 use crate::values::IntValue;
 use crate::AddressSpace;
 
-// Worth noting that types seem to be singletons. At the very least, primitives are.
-// Though this is likely only true per thread since LLVM claims to not be very thread-safe.
+// Types are uniqued per context (named structs aside), so comparing the underlying
+// pointers is a structural comparison within a single context.
 #[derive(PartialEq, Eq, Clone, Copy)]
 struct Type<'ctx> {
     ty: LLVMTypeRef,
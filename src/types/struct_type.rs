@@ -35,7 +35,8 @@ impl<'ctx> StructType<'ctx> {
         }
     }
 
-    /// Gets the type of a field belonging to this `StructType`.
+    /// Gets the type of a field belonging to this `StructType`. Returns `None` if the
+    /// index is out of range or the struct is opaque, rather than reading out of bounds.
     ///
     /// # Example
     ///
@@ -47,6 +48,7 @@ impl<'ctx> StructType<'ctx> {
     /// let struct_type = context.struct_type(&[f32_type.into()], false);
     ///
     /// assert_eq!(struct_type.get_field_type_at_index(0).unwrap().into_float_type(), f32_type);
+    /// assert!(struct_type.get_field_type_at_index(1).is_none());
     /// ```
     pub fn get_field_type_at_index(self, index: u32) -> Option<BasicTypeEnum<'ctx>> {
         // LLVM doesn't seem to just return null if opaque.
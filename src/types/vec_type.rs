@@ -5,7 +5,7 @@ use crate::context::ContextRef;
 use crate::support::LLVMString;
 use crate::types::enums::BasicMetadataTypeEnum;
 use crate::types::{traits::AsTypeRef, ArrayType, BasicTypeEnum, FunctionType, PointerType, Type};
-use crate::values::{ArrayValue, BasicValue, IntValue, VectorValue};
+use crate::values::{ArrayValue, AsValueRef, BasicValue, IntValue, VectorValue};
 use crate::AddressSpace;
 
 use std::fmt::{self, Display};
@@ -110,6 +110,37 @@ impl<'ctx> VectorType<'ctx> {
         unsafe { VectorValue::new(LLVMConstVector(values.as_mut_ptr(), values.len() as u32)) }
     }
 
+    /// Creates a constant `VectorValue` of this `VectorType` with every lane set to `scalar`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is not of this `VectorType`'s element type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let f32_vec_type = f32_type.vec_type(4);
+    /// let f32_splat = f32_vec_type.const_splat(f32_type.const_float(1.));
+    ///
+    /// assert_eq!(f32_splat.get_type(), f32_vec_type);
+    /// ```
+    pub fn const_splat<V: BasicValue<'ctx>>(self, scalar: V) -> VectorValue<'ctx> {
+        let scalar = scalar.as_basic_value_enum();
+
+        assert_eq!(
+            scalar.get_type(),
+            self.get_element_type(),
+            "A splat scalar must match the vector's element type."
+        );
+
+        let mut values = vec![scalar.as_value_ref(); self.get_size() as usize];
+
+        unsafe { VectorValue::new(LLVMConstVector(values.as_mut_ptr(), values.len() as u32)) }
+    }
+
     /// Creates a constant zero value of this `VectorType`.
     ///
     /// # Example
@@ -4,10 +4,11 @@ use llvm_sys::core::{
     LLVMRemoveEnumAttributeAtIndex, LLVMRemoveStringAttributeAtIndex,
 };
 use llvm_sys::core::{
-    LLVMCountBasicBlocks, LLVMCountParams, LLVMDeleteFunction, LLVMGetBasicBlocks, LLVMGetFirstBasicBlock,
-    LLVMGetFirstParam, LLVMGetFunctionCallConv, LLVMGetGC, LLVMGetIntrinsicID, LLVMGetLastBasicBlock, LLVMGetLastParam,
-    LLVMGetLinkage, LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam, LLVMGetParams, LLVMGetPreviousFunction,
-    LLVMIsAFunction, LLVMIsConstant, LLVMSetFunctionCallConv, LLVMSetGC, LLVMSetLinkage, LLVMSetParamAlignment,
+    LLVMCountBasicBlocks, LLVMCountParams, LLVMDeleteFunction, LLVMGetBasicBlocks, LLVMGetEntryBasicBlock,
+    LLVMGetFirstBasicBlock, LLVMGetFirstParam, LLVMGetFunctionCallConv, LLVMGetGC, LLVMGetIntrinsicID,
+    LLVMGetLastBasicBlock, LLVMGetLastParam, LLVMGetLinkage, LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam,
+    LLVMGetParams, LLVMGetPreviousFunction, LLVMIsAFunction, LLVMIsConstant, LLVMSetFunctionCallConv, LLVMSetGC,
+    LLVMSetLinkage, LLVMSetParamAlignment,
 };
 use llvm_sys::core::{LLVMGetPersonalityFn, LLVMSetPersonalityFn};
 #[llvm_versions(7..)]
@@ -72,6 +73,9 @@ impl<'ctx> FunctionValue<'ctx> {
         self.fn_value.print_to_stderr()
     }
 
+    /// Runs the LLVM verifier on just this function, returning `true` if it is well formed.
+    /// Unlike `Module::verify`, this localizes errors to a single function. If `print` is
+    /// `true`, verifier messages are printed to stderr.
     // FIXME: Better error returns, code 1 is error
     pub fn verify(self, print: bool) -> bool {
         let action = if print {
@@ -118,6 +122,36 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { BasicBlock::new(LLVMGetFirstBasicBlock(self.as_value_ref())) }
     }
 
+    /// Gets the entry `BasicBlock` of this function, which is always its first block, or `None`
+    /// if this function is only a declaration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let function = module.add_function("my_fn", fn_type, None);
+    ///
+    /// assert!(function.get_entry_basic_block().is_none());
+    ///
+    /// let entry = context.append_basic_block(function, "entry");
+    ///
+    /// context.append_basic_block(function, "exit");
+    ///
+    /// assert_eq!(function.get_entry_basic_block(), Some(entry));
+    /// ```
+    pub fn get_entry_basic_block(self) -> Option<BasicBlock<'ctx>> {
+        // LLVM doesn't check for an empty body before returning the entry block
+        if self.count_basic_blocks() == 0 {
+            return None;
+        }
+
+        unsafe { BasicBlock::new(LLVMGetEntryBasicBlock(self.as_value_ref())) }
+    }
+
     pub fn get_nth_param(self, nth: u32) -> Option<BasicValueEnum<'ctx>> {
         let count = self.count_params();
 
@@ -192,6 +226,12 @@ impl<'ctx> FunctionValue<'ctx> {
         self.fn_value.get_name()
     }
 
+    /// Sets the name of a `FunctionValue`. If another global value in the module already has this name,
+    /// LLVM renames this function by appending a unique suffix.
+    pub fn set_name(&self, name: &str) {
+        self.fn_value.set_name(name)
+    }
+
     /// View the control flow graph and produce a .dot file
     pub fn view_function_cfg(self) {
         unsafe { LLVMViewFunctionCFG(self.as_value_ref()) }
@@ -202,6 +242,12 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMViewFunctionCFGOnly(self.as_value_ref()) }
     }
 
+    /// Removes this function from its module and frees it, along with all of its basic blocks.
+    ///
+    /// # Safety
+    ///
+    /// Any other handle to this function, its parameters, basic blocks or instructions is
+    /// dangling afterwards and must not be used. Calls to this function must be removed first.
     // TODO: Look for ways to prevent use after delete but maybe not possible
     pub unsafe fn delete(self) {
         LLVMDeleteFunction(self.as_value_ref())
@@ -245,10 +291,14 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMGetIntrinsicID(self.as_value_ref()) }
     }
 
+    /// Gets the calling convention of this function, which is 0 (the C convention) unless it has been set.
     pub fn get_call_conventions(self) -> u32 {
         unsafe { LLVMGetFunctionCallConv(self.as_value_ref()) }
     }
 
+    /// Sets the calling convention of this function. The values are LLVM's calling convention ids,
+    /// such as 0 for `ccc`, 8 for `fastcc`, 9 for `coldcc` and 64 for `x86_stdcallcc`. Calls to this
+    /// function should use the same convention, see `CallSiteValue::set_call_convention`.
     pub fn set_call_conventions(self, call_conventions: u32) {
         unsafe { LLVMSetFunctionCallConv(self.as_value_ref(), call_conventions) }
     }
@@ -161,6 +161,33 @@ fn test_attributes_on_function_values() {
     assert_eq!(fn_value.attributes(AttributeLoc::Return), vec![]);
 }
 
+#[test]
+fn test_function_attributes_in_ir() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let always_inline = context.create_enum_attribute(Attribute::get_named_enum_kind_id("alwaysinline"), 0);
+    let no_unwind = context.create_enum_attribute(Attribute::get_named_enum_kind_id("nounwind"), 0);
+    let target_features = context.create_string_attribute("target-features", "+avx2");
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+    builder.build_return(None).unwrap();
+
+    fn_value.add_attribute(AttributeLoc::Function, always_inline);
+    fn_value.add_attribute(AttributeLoc::Function, no_unwind);
+    fn_value.add_attribute(AttributeLoc::Function, target_features);
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 3);
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("define void @my_fn() #0 {"));
+    assert!(ir.contains("attributes #0 = { alwaysinline nounwind \"target-features\"=\"+avx2\" }"));
+}
+
 #[test]
 fn test_attributes_on_call_site_values() {
     let context = Context::create();
@@ -237,3 +264,53 @@ fn test_attributes_on_call_site_values() {
         .get_enum_attribute(AttributeLoc::Return, align_attribute)
         .is_some());
 }
+
+#[test]
+fn test_call_site_argument_attributes_in_ir() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = i32_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+    let callee = module.add_function("callee", context.void_type().fn_type(&[ptr_type.into()], false), None);
+    let caller = module.add_function("caller", context.void_type().fn_type(&[], false), None);
+    let no_alias = context.create_enum_attribute(Attribute::get_named_enum_kind_id("noalias"), 0);
+
+    builder.position_at_end(context.append_basic_block(caller, "entry"));
+
+    let slot = builder.build_alloca(i32_type, "slot").unwrap();
+    let call_site_value = builder.build_call(callee, &[slot.into()], "").unwrap();
+
+    builder.build_return(None).unwrap();
+
+    call_site_value.add_attribute(AttributeLoc::Param(0), no_alias);
+
+    assert_eq!(call_site_value.count_attributes(AttributeLoc::Param(0)), 1);
+    assert_eq!(callee.count_attributes(AttributeLoc::Param(0)), 0);
+    assert!(module.verify().is_ok());
+
+    // The attribute only applies to this call, not to the callee's declaration
+    let ir = module.print_to_string().to_string();
+
+    let call_line = ir.lines().find(|line| line.contains("call void @callee")).unwrap();
+    let declare_line = ir
+        .lines()
+        .find(|line| line.starts_with("declare void @callee"))
+        .unwrap();
+
+    assert!(call_line.ends_with(" noalias %slot)"));
+    assert!(!declare_line.contains("noalias"));
+}
@@ -75,6 +75,32 @@ fn test_basic_block_ordering() {
     assert_eq!(basic_block5.get_name().to_str(), Ok("block5"));
 }
 
+#[test]
+fn test_entry_basic_block_of_three() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("three_blocks", fn_type, None);
+
+    assert!(function.get_entry_basic_block().is_none());
+    assert_eq!(function.count_basic_blocks(), 0);
+
+    let entry = context.append_basic_block(function, "entry");
+    let body = context.append_basic_block(function, "body");
+    let exit = context.append_basic_block(function, "exit");
+
+    assert_eq!(function.count_basic_blocks(), 3);
+    assert_eq!(function.get_basic_blocks(), [entry, body, exit]);
+    assert_eq!(function.get_entry_basic_block(), Some(entry));
+    assert_eq!(function.get_first_basic_block(), Some(entry));
+
+    // A block prepended before the entry block becomes the new entry
+    let prologue = context.prepend_basic_block(entry, "prologue");
+
+    assert_eq!(function.get_basic_blocks(), [prologue, entry, body, exit]);
+    assert_eq!(function.get_entry_basic_block(), Some(prologue));
+}
+
 #[test]
 fn test_get_basic_blocks() {
     let context = Context::create();
@@ -104,6 +130,41 @@ fn test_get_basic_blocks() {
         assert_eq!(basic_blocks.len(), 1);
         assert_eq!(basic_blocks[0], basic_block);
     }
+
+    let exit_block = context.append_basic_block(function, "exit");
+
+    assert_eq!(function.get_first_basic_block(), Some(basic_block));
+    assert_eq!(function.get_last_basic_block(), Some(exit_block));
+    assert_eq!(basic_block.get_next_basic_block(), Some(exit_block));
+
+    for basic_blocks in [function.get_basic_blocks(), function.get_basic_block_iter().collect()] {
+        assert_eq!(basic_blocks, [basic_block, exit_block]);
+    }
+
+    let names: Vec<_> = function
+        .get_basic_block_iter()
+        .map(|block| block.get_name().to_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(names, ["entry", "exit"]);
+
+    // Neither block is terminated until a terminator instruction is built into it
+    let builder = context.create_builder();
+
+    assert!(basic_block.get_terminator().is_none());
+    assert!(exit_block.get_terminator().is_none());
+
+    builder.position_at_end(basic_block);
+    builder.build_unconditional_branch(exit_block).unwrap();
+
+    assert!(basic_block.get_terminator().is_some());
+    assert!(exit_block.get_terminator().is_none());
+
+    builder.position_at_end(exit_block);
+    builder.build_return(Some(&bool_type.const_zero())).unwrap();
+
+    assert!(exit_block.get_terminator().is_some());
+    assert!(function.verify(false));
 }
 
 #[test]
@@ -1,6 +1,7 @@
 use inkwell::builder::BuilderError;
 use inkwell::context::Context;
-use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, OptimizationLevel};
+use inkwell::values::{AnyValue, InstructionOpcode};
+use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate, OptimizationLevel};
 
 use std::ptr::null;
 
@@ -123,6 +124,56 @@ fn test_build_call() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_build_call_with_args_and_void_callee() {
+    let context = Context::create();
+    let module = context.create_module("calls");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+
+    let add_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let add = module.add_function("add", add_type, None);
+
+    builder.position_at_end(context.append_basic_block(add, "entry"));
+
+    let lhs = add.get_nth_param(0).unwrap().into_int_value();
+    let rhs = add.get_nth_param(1).unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum").unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    let log = module.add_function("log", void_type.fn_type(&[i32_type.into()], false), None);
+    let caller = module.add_function("caller", i32_type.fn_type(&[], false), None);
+
+    builder.position_at_end(context.append_basic_block(caller, "entry"));
+
+    let one = i32_type.const_int(1, false);
+    let two = i32_type.const_int(2, false);
+    let call = builder.build_call(add, &[one.into(), two.into()], "call").unwrap();
+
+    // The callee operand is not counted as an argument
+    assert_eq!(call.count_arguments(), 2);
+    assert_eq!(call.get_called_fn_value(), add);
+
+    let result = call.try_as_basic_value().left().unwrap();
+
+    // Calls to void functions produce no usable value, so they must be left unnamed
+    let void_call = builder.build_call(log, &[result.into()], "").unwrap();
+
+    assert!(void_call.try_as_basic_value().is_right());
+    assert_eq!(void_call.count_arguments(), 1);
+
+    builder.build_return(Some(&result)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = caller.print_to_string().to_string();
+
+    assert!(ir.contains("%call = call i32 @add(i32 1, i32 2)"));
+    assert!(ir.contains("call void @log(i32 %call)"));
+}
+
 #[test]
 fn test_build_invoke_cleanup_resume() {
     let context = Context::create();
@@ -296,7 +347,6 @@ fn test_build_invoke_catch_all() {
 #[test]
 fn landing_pad_filter() {
     use inkwell::module::Linkage;
-    use inkwell::values::AnyValue;
 
     let context = Context::create();
     let module = context.create_module("sum");
@@ -642,6 +692,274 @@ fn test_binary_ops() {
     }
 }
 
+#[test]
+fn test_arithmetic_ops() {
+    let context = Context::create();
+    let module = context.create_module("arithmetic");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    // fn mul_add(a: i32, b: i32, c: i32) -> i32 { a + b * c }
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("mul_add", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let c = fn_value.get_nth_param(2).unwrap().into_int_value();
+
+    a.set_name("a");
+    b.set_name("b");
+    c.set_name("c");
+
+    let product = builder.build_int_mul(b, c, "product").unwrap();
+    let sum = builder.build_int_add(a, product, "sum").unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    // fn divs(a: i32, b: i32) -> i32 { (a / b) - (a as u32 / b as u32) }
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("divs", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let a = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let signed = builder.build_int_signed_div(a, b, "signed").unwrap();
+    let unsigned = builder.build_int_unsigned_div(a, b, "unsigned").unwrap();
+    let diff = builder.build_int_sub(signed, unsigned, "diff").unwrap();
+
+    builder.build_return(Some(&diff)).unwrap();
+
+    // fn fmul_add(a: f64, b: f64, c: f64) -> f64 { (a + b * c - a) / c }
+    let fn_type = f64_type.fn_type(&[f64_type.into(), f64_type.into(), f64_type.into()], false);
+    let fn_value = module.add_function("fmul_add", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let a = fn_value.get_nth_param(0).unwrap().into_float_value();
+    let b = fn_value.get_nth_param(1).unwrap().into_float_value();
+    let c = fn_value.get_nth_param(2).unwrap().into_float_value();
+    let product = builder.build_float_mul(b, c, "product").unwrap();
+    let sum = builder.build_float_add(a, product, "sum").unwrap();
+    let diff = builder.build_float_sub(sum, a, "diff").unwrap();
+    let quotient = builder.build_float_div(diff, c, "quotient").unwrap();
+
+    builder.build_return(Some(&quotient)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%product = mul i32 %b, %c\n  %sum = add i32 %a, %product\n  ret i32 %sum"));
+    assert!(ir.contains("%signed = sdiv i32 %0, %1"));
+    assert!(ir.contains("%unsigned = udiv i32 %0, %1"));
+    assert!(ir.contains("%diff = sub i32 %signed, %unsigned"));
+    assert!(ir.contains("%product = fmul double %1, %2"));
+    assert!(ir.contains("%sum = fadd double %0, %product"));
+    assert!(ir.contains("%diff = fsub double %sum, %0"));
+    assert!(ir.contains("%quotient = fdiv double %diff, %2"));
+}
+
+#[test]
+fn test_alloca_store_load_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("memory");
+    let builder = context.create_builder();
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let i32_type = context.i32_type();
+    let pair_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+
+    // fn round_trip(x: i32) -> i32 { let mut pair = (0, 0); pair.1 = x; pair.1 }
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("round_trip", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+    let pair = builder.build_alloca(pair_type, "pair").unwrap();
+
+    builder.build_store(pair, pair_type.const_zero()).unwrap();
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let second = builder.build_struct_gep(pair, 1, "second").unwrap();
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let second = builder.build_struct_gep(pair_type, pair, 1, "second").unwrap();
+
+    // Out of bounds fields are rejected rather than producing invalid IR
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert!(builder.build_struct_gep(pair, 2, "third").is_err());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    assert!(builder.build_struct_gep(pair_type, pair, 2, "third").is_err());
+
+    builder.build_store(second, x).unwrap();
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let loaded = builder.build_load(second, "loaded").unwrap();
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let loaded = builder.build_load(i32_type, second, "loaded").unwrap();
+
+    builder.build_return(Some(&loaded)).unwrap();
+
+    assert!(fn_value.verify(false));
+
+    let ir = fn_value.print_to_string().to_string();
+
+    assert!(ir.contains("%pair = alloca { i32, i32 }"));
+    assert!(ir.contains("getelementptr inbounds { i32, i32 }"));
+    assert!(ir.contains("%loaded = load i32"));
+
+    unsafe {
+        let round_trip = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("round_trip")
+            .unwrap();
+
+        assert_eq!(round_trip.call(42), 42);
+        assert_eq!(round_trip.call(-7), -7);
+    }
+}
+
+#[test]
+fn test_compare_ops() {
+    let context = Context::create();
+    let module = context.create_module("compare");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let bool_type = context.bool_type();
+
+    let fn_type = bool_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("signed_lt", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let lhs = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let rhs = fn_value.get_nth_param(1).unwrap().into_int_value();
+    let slt = builder.build_int_compare(IntPredicate::SLT, lhs, rhs, "slt").unwrap();
+
+    builder.build_return(Some(&slt)).unwrap();
+
+    assert_eq!(slt.get_type(), bool_type);
+    assert_eq!(
+        slt.as_instruction().unwrap().get_icmp_predicate(),
+        Some(IntPredicate::SLT)
+    );
+
+    let fn_type = bool_type.fn_type(&[f64_type.into(), f64_type.into()], false);
+    let fn_value = module.add_function("ordered_eq", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let lhs = fn_value.get_nth_param(0).unwrap().into_float_value();
+    let rhs = fn_value.get_nth_param(1).unwrap().into_float_value();
+    let oeq = builder
+        .build_float_compare(FloatPredicate::OEQ, lhs, rhs, "oeq")
+        .unwrap();
+
+    builder.build_return(Some(&oeq)).unwrap();
+
+    assert_eq!(oeq.get_type(), bool_type);
+    assert_eq!(
+        oeq.as_instruction().unwrap().get_fcmp_predicate(),
+        Some(FloatPredicate::OEQ)
+    );
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%slt = icmp slt i32 %0, %1"));
+    assert!(ir.contains("%oeq = fcmp oeq double %0, %1"));
+}
+
+#[test]
+fn test_loop_counter_phi() {
+    let context = Context::create();
+    let module = context.create_module("loops");
+    let builder = context.create_builder();
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let i32_type = context.i32_type();
+
+    // fn count_to(n: i32) -> i32 { let mut i = 0; while i < n { i += 1 } i }
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("count_to", fn_type, None);
+    let entry_bb = context.append_basic_block(fn_value, "entry");
+    let loop_bb = context.append_basic_block(fn_value, "loop");
+    let exit_bb = context.append_basic_block(fn_value, "exit");
+    let n = fn_value.get_first_param().unwrap().into_int_value();
+    let zero = i32_type.const_zero();
+
+    builder.position_at_end(entry_bb);
+    builder.build_unconditional_branch(loop_bb).unwrap();
+
+    builder.position_at_end(loop_bb);
+
+    let counter = builder.build_phi(i32_type, "counter").unwrap();
+    let counter_val = counter.as_basic_value().into_int_value();
+    let is_done = builder
+        .build_int_compare(IntPredicate::SGE, counter_val, n, "is_done")
+        .unwrap();
+    let next = builder
+        .build_int_add(counter_val, i32_type.const_int(1, false), "next")
+        .unwrap();
+
+    counter.add_incoming(&[(&zero, entry_bb), (&next, loop_bb)]);
+    builder.build_conditional_branch(is_done, exit_bb, loop_bb).unwrap();
+
+    builder.position_at_end(exit_bb);
+    builder.build_return(Some(&counter_val)).unwrap();
+
+    assert!(fn_value.verify(false));
+    assert_eq!(counter.count_incoming(), 2);
+    assert_eq!(counter.get_incoming(0).unwrap().1, entry_bb);
+    assert_eq!(counter.get_incoming(1).unwrap().1, loop_bb);
+    assert_eq!(
+        counter.print_to_string().to_str(),
+        Ok("  %counter = phi i32 [ 0, %entry ], [ %next, %loop ]")
+    );
+
+    unsafe {
+        let count_to = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("count_to")
+            .unwrap();
+
+        assert_eq!(count_to.call(0), 0);
+        assert_eq!(count_to.call(10), 10);
+    }
+}
+
 #[test]
 fn test_switch() {
     let context = Context::create();
@@ -821,6 +1139,71 @@ fn test_unconditional_branch() {
     builder.build_unreachable().unwrap();
 }
 
+#[test]
+fn test_position_and_build_return() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    // An unpositioned builder refuses to emit instructions
+    assert_eq!(builder.build_return(None), Err(BuilderError::UnsetPosition));
+    assert!(builder.get_insert_block().is_none());
+
+    builder.position_at_end(entry);
+
+    assert_eq!(builder.get_insert_block(), Some(entry));
+
+    let ret = builder.build_return(None).unwrap();
+
+    assert_eq!(entry.get_terminator(), Some(ret));
+    assert!(module.verify().is_ok());
+    assert_eq!(
+        fn_value.print_to_string().to_str(),
+        Ok("define void @my_fn() {\nentry:\n  ret void\n}\n")
+    );
+}
+
+#[test]
+fn test_conditional_branch() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let bool_type = context.bool_type();
+
+    // fn select(cond: bool) -> i32 { if cond { 1 } else { 2 } }
+    let fn_type = i32_type.fn_type(&[bool_type.into()], false);
+    let fn_value = module.add_function("select", fn_type, None);
+    let entry_bb = context.append_basic_block(fn_value, "entry");
+    let then_bb = context.append_basic_block(fn_value, "then");
+    let else_bb = context.append_basic_block(fn_value, "else");
+    let cond = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry_bb);
+    builder.build_conditional_branch(cond, then_bb, else_bb).unwrap();
+
+    builder.position_at_end(then_bb);
+    builder.build_return(Some(&i32_type.const_int(1, false))).unwrap();
+
+    builder.position_at_end(else_bb);
+    builder.build_return(Some(&i32_type.const_int(2, false))).unwrap();
+
+    assert!(fn_value.verify(false));
+
+    let ir = fn_value.print_to_string().to_string();
+
+    assert!(ir.contains("br i1 %0, label %then, label %else"));
+    assert!(ir.contains("\nthen:"));
+    assert!(ir.contains("ret i32 1"));
+    assert!(ir.contains("\nelse:"));
+    assert!(ir.contains("ret i32 2"));
+    assert_eq!(then_bb.get_terminator().unwrap().get_num_operands(), 1);
+    assert_eq!(entry_bb.get_terminator().unwrap().get_num_operands(), 3);
+}
+
 #[test]
 fn test_no_builder_double_free() {
     let context = Context::create();
@@ -1186,6 +1569,56 @@ fn test_insert_value() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_insert_then_extract_struct_field() {
+    let context = Context::create();
+    let module = context.create_module("pair");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let pair_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let function = module.add_function("second", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let first = function.get_first_param().unwrap().into_int_value();
+    let second = function.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    // The pair only ever lives in registers, no alloca is needed
+    let pair = builder
+        .build_insert_value(pair_type.get_undef(), first, 0, "pair.0")
+        .unwrap();
+    let pair = builder.build_insert_value(pair, second, 1, "pair.1").unwrap();
+
+    assert!(pair.is_struct_value());
+    assert_eq!(pair.into_struct_value().get_type(), pair_type);
+
+    let field = builder.build_extract_value(pair, 1, "field").unwrap();
+
+    assert!(field.is_int_value());
+
+    builder.build_return(Some(&field)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("%pair.0 = insertvalue { i32, i32 } undef, i32 %0, 0"));
+    assert!(ir.contains("%pair.1 = insertvalue { i32, i32 } %pair.0, i32 %1, 1"));
+    assert!(ir.contains("%field = extractvalue { i32, i32 } %pair.1, 1"));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let second = execution_engine
+            .get_function::<unsafe extern "C" fn(i32, i32) -> i32>("second")
+            .unwrap();
+
+        assert_eq!(second.call(3, 4), 4);
+        assert_eq!(second.call(-1, 7), 7);
+    }
+}
+
 #[test]
 fn test_insert_element() {
     use inkwell::types::IntType;
@@ -1239,6 +1672,64 @@ fn is_alignment_ok(align: u32) -> bool {
     align > 0 && align.is_power_of_two() && (align as f64).log2() < 64.0
 }
 
+#[test]
+fn test_vector_element_and_shuffle_ops() {
+    use inkwell::types::VectorType;
+
+    let context = Context::create();
+    let module = context.create_module("vec");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let vec_type = i32_type.vec_type(4);
+    let fn_type = i32_type.fn_type(&[vec_type.into(), i32_type.into()], false);
+    let function = module.add_function("vec_fn", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let vector = function.get_first_param().unwrap().into_vector_value();
+    let element = function.get_last_param().unwrap().into_int_value();
+    let index = i32_type.const_int(2, false);
+
+    builder.position_at_end(entry);
+
+    let inserted = builder
+        .build_insert_element(vector, element, index, "inserted")
+        .unwrap();
+
+    assert_eq!(inserted.get_type(), vec_type);
+
+    // Reverse the lanes of the updated vector
+    let mask: Vec<_> = (0..4).rev().map(|i| i32_type.const_int(i, false)).collect();
+    let reversed = builder
+        .build_shuffle_vector(
+            inserted,
+            vec_type.get_undef(),
+            VectorType::const_vector(&mask),
+            "reversed",
+        )
+        .unwrap();
+
+    assert_eq!(reversed.get_type(), vec_type);
+
+    // Lane 2 lands in lane 1 once reversed
+    let extracted = builder
+        .build_extract_element(reversed, i32_type.const_int(1, false), "extracted")
+        .unwrap();
+
+    assert!(extracted.is_int_value());
+
+    builder.build_return(Some(&extracted)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("%inserted = insertelement <4 x i32> %0, i32 %1, i32 2"));
+    assert!(ir.contains(
+        "%reversed = shufflevector <4 x i32> %inserted, <4 x i32> undef, \
+         <4 x i32> <i32 3, i32 2, i32 1, i32 0>"
+    ));
+    assert!(ir.contains("%extracted = extractelement <4 x i32> %reversed, i32 1"));
+}
+
 #[llvm_versions(8..)]
 #[test]
 fn test_alignment_bytes() {
@@ -1381,6 +1872,62 @@ fn run_memcpy_on<'ctx>(
     Ok(())
 }
 
+#[llvm_versions(8..)]
+#[test]
+fn test_memcpy_between_allocas_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("copy");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let array_type = i32_type.array_type(4);
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("copy", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+
+    let src = builder.build_alloca(array_type, "src").unwrap();
+    let dest = builder.build_alloca(array_type, "dest").unwrap();
+    let size = i64_type.const_int(16, false);
+
+    assert!(builder.build_memcpy(dest, 3, src, 4, size).is_err());
+
+    let memcpy = builder.build_memcpy(dest, 4, src, 4, size).unwrap();
+
+    // The returned value wraps the intrinsic call itself
+    let call = memcpy.as_instruction().unwrap();
+
+    assert_eq!(call.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    {
+        assert!(ir.contains("call void @llvm.memcpy.p0i8.p0i8.i64(i8* align 4 "));
+        assert!(ir.contains("declare void @llvm.memcpy.p0i8.p0i8.i64("));
+    }
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    {
+        assert!(ir.contains("call void @llvm.memcpy.p0.p0.i64(ptr align 4 %dest, ptr align 4 %src, i64 16, i1 false)"));
+        assert!(ir.contains("declare void @llvm.memcpy.p0.p0.i64("));
+    }
+}
+
 #[llvm_versions(8..)]
 #[test]
 fn test_memcpy() {
@@ -1720,6 +2267,424 @@ fn test_bit_cast() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_position_before_and_clear_position() {
+    let context = Context::create();
+    let module = context.create_module("rewrite");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("rewrite", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let arg = function.get_first_param().unwrap().into_int_value();
+
+    assert!(builder.get_insert_block().is_none());
+
+    builder.position_at_end(entry);
+
+    assert_eq!(builder.get_insert_block(), Some(entry));
+
+    let ret = builder.build_return(Some(&arg)).unwrap();
+
+    // Rewrite the function to return a doubled value by inserting ahead of the existing return
+    builder.position_before(&ret);
+
+    assert_eq!(builder.get_insert_block(), Some(entry));
+
+    let doubled = builder.build_int_add(arg, arg, "doubled").unwrap();
+
+    ret.set_operand(0, doubled);
+
+    let first = entry.get_first_instruction().unwrap();
+
+    assert_eq!(Some(first), doubled.as_instruction());
+    assert_eq!(first.get_next_instruction(), Some(ret));
+    assert_eq!(entry.get_last_instruction(), Some(ret));
+    assert!(module.verify().is_ok());
+    assert!(function
+        .print_to_string()
+        .to_string()
+        .contains("%doubled = add i32 %0, %0\n  ret i32 %doubled\n"));
+
+    builder.clear_insertion_position();
+
+    assert!(builder.get_insert_block().is_none());
+    assert_eq!(
+        builder.build_int_add(arg, arg, "unplaced").err(),
+        Some(BuilderError::UnsetPosition)
+    );
+    assert_eq!(entry.get_instructions().count(), 2);
+}
+
+#[llvm_versions(15..)]
+#[test]
+fn test_opaque_pointer_load_store_gep() {
+    let context = Context::create();
+    let module = context.create_module("opaque");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let ptr_type = context.ptr_type(AddressSpace::default());
+
+    assert!(ptr_type.is_opaque());
+
+    let fn_type = i32_type.fn_type(&[ptr_type.into()], false);
+    let function = module.add_function("second_elem", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let ptr = function.get_first_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(entry);
+
+    // The pointer carries no element type, so every access names the type explicitly
+    let elem = unsafe {
+        builder
+            .build_gep(i32_type, ptr, &[i64_type.const_int(1, false)], "elem")
+            .unwrap()
+    };
+
+    assert_eq!(elem.get_type(), ptr_type);
+
+    let value = builder.build_load(i32_type, elem, "value").unwrap();
+
+    assert_eq!(value.get_type(), i32_type.into());
+
+    builder.build_store(ptr, value).unwrap();
+    builder.build_return(Some(&value)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("define i32 @second_elem(ptr %0)"));
+    assert!(ir.contains("%elem = getelementptr i32, ptr %0, i64 1"));
+    assert!(ir.contains("%value = load i32, ptr %elem"));
+    assert!(ir.contains("store i32 %value, ptr %0"));
+}
+
+#[test]
+fn test_build_aggregate_return_validates_arity() {
+    let context = Context::create();
+    let module = context.create_module("ret");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let pair_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_type = pair_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let function = module.add_function("swap", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let a = function.get_first_param().unwrap();
+    let b = function.get_last_param().unwrap();
+
+    builder.position_at_end(entry);
+
+    assert!(matches!(
+        builder.build_aggregate_return(&[a]),
+        Err(BuilderError::ValueTypeMismatch(_))
+    ));
+    assert!(matches!(
+        builder.build_aggregate_return(&[a, b, a]),
+        Err(BuilderError::ValueTypeMismatch(_))
+    ));
+
+    let ret = builder.build_aggregate_return(&[b, a]).unwrap();
+
+    assert_eq!(ret.get_opcode(), InstructionOpcode::Return);
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("define { i32, i32 } @swap(i32 %0, i32 %1)"));
+    assert!(ir.contains("insertvalue { i32, i32 } "));
+    assert!(ir.contains(", i32 %1, 0\n"));
+    assert!(ir.contains("ret { i32, i32 } %"));
+
+    // A function which doesn't return an aggregate is rejected rather than crashing LLVM
+    let void_fn = module.add_function("nothing", context.void_type().fn_type(&[], false), None);
+    let entry = context.append_basic_block(void_fn, "entry");
+
+    builder.position_at_end(entry);
+
+    assert!(matches!(
+        builder.build_aggregate_return(&[a]),
+        Err(BuilderError::ValueTypeMismatch(_))
+    ));
+}
+
+#[test]
+fn test_build_switch_cases_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("switch");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("dispatch", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let default = context.append_basic_block(function, "default");
+    let case_blocks: Vec<_> = ["zero", "one", "two"]
+        .iter()
+        .map(|name| context.append_basic_block(function, name))
+        .collect();
+    let cases: Vec<_> = case_blocks
+        .iter()
+        .enumerate()
+        .map(|(i, &block)| (i32_type.const_int(i as u64, false), block))
+        .collect();
+    let value = function.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let switch = builder.build_switch(value, default, &cases).unwrap();
+
+    assert_eq!(switch.get_opcode(), InstructionOpcode::Switch);
+    assert_eq!(switch.get_num_operands(), 2 + 2 * cases.len() as u32);
+
+    for (i, &block) in case_blocks.iter().enumerate() {
+        builder.position_at_end(block);
+        builder
+            .build_return(Some(&i32_type.const_int(i as u64 * 10, false)))
+            .unwrap();
+    }
+
+    builder.position_at_end(default);
+    builder.build_return(Some(&i32_type.const_all_ones())).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("switch i32 %0, label %default ["));
+    assert!(ir.contains("i32 0, label %zero"));
+    assert!(ir.contains("i32 1, label %one"));
+    assert!(ir.contains("i32 2, label %two"));
+}
+
+#[test]
+fn test_build_select_and_unreachable_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("select");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+
+    let max_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let max_fn = module.add_function("max", max_type, None);
+    let entry = context.append_basic_block(max_fn, "entry");
+    let a = max_fn.get_first_param().unwrap().into_int_value();
+    let b = max_fn.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let a_greater = builder.build_int_compare(IntPredicate::SGT, a, b, "a_greater").unwrap();
+    let max = builder.build_select(a_greater, a, b, "max").unwrap();
+
+    assert!(max.is_int_value());
+
+    builder.build_return(Some(&max)).unwrap();
+
+    let abort_fn = module.add_function("abort", void_type.fn_type(&[], false), None);
+    let fail_fn = module.add_function("fail", void_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(fail_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_call(abort_fn, &[], "").unwrap();
+
+    let unreachable = builder.build_unreachable().unwrap();
+
+    assert_eq!(unreachable.get_opcode(), InstructionOpcode::Unreachable);
+    assert!(unreachable.is_terminator());
+    assert_eq!(entry.get_terminator(), Some(unreachable));
+    assert!(module.verify().is_ok());
+
+    let max_ir = max_fn.print_to_string().to_string();
+
+    assert!(max_ir.contains("%a_greater = icmp sgt i32 %0, %1"));
+    assert!(max_ir.contains("%max = select i1 %a_greater, i32 %0, i32 %1"));
+    assert!(max_ir.contains("ret i32 %max"));
+
+    let fail_ir = fail_fn.print_to_string().to_string();
+
+    assert!(fail_ir.contains("call void @abort()\n  unreachable\n"));
+}
+
+#[test]
+fn test_build_global_string_ptr_call_puts() {
+    let context = Context::create();
+    let module = context.create_module("hello");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let str_type = context.i8_type().ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let str_type = context.ptr_type(AddressSpace::default());
+    let puts_fn = module.add_function("puts", i32_type.fn_type(&[str_type.into()], false), None);
+    let main_fn = module.add_function("main", i32_type.fn_type(&[], false), None);
+    let entry = context.append_basic_block(main_fn, "entry");
+
+    // Requires a position, since the string is built relative to the current function's module
+    assert_eq!(
+        builder.build_global_string_ptr("hello", "greeting").err(),
+        Some(BuilderError::UnsetPosition)
+    );
+
+    builder.position_at_end(entry);
+
+    let greeting = builder.build_global_string_ptr("hello", "greeting").unwrap();
+    let greeting_ptr = greeting.as_pointer_value();
+
+    assert_eq!(greeting_ptr.get_type(), str_type);
+
+    builder.build_call(puts_fn, &[greeting_ptr.into()], "").unwrap();
+    builder.build_return(Some(&i32_type.const_zero())).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@greeting = private unnamed_addr constant [6 x i8] c\"hello\\00\""));
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert!(ir.contains("call i32 @puts(i8* getelementptr inbounds ([6 x i8], [6 x i8]* @greeting, i32 0, i32 0))"));
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    assert!(ir.contains("call i32 @puts(ptr @greeting)"));
+}
+
+#[test]
+fn test_bitwise_and_shift_opcodes() {
+    let context = Context::create();
+    let module = context.create_module("bits");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let function = module.add_function("bits", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let a = function.get_first_param().unwrap().into_int_value();
+    let b = function.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let and = builder.build_and(a, b, "and").unwrap();
+    let or = builder.build_or(a, b, "or").unwrap();
+    let xor = builder.build_xor(and, or, "xor").unwrap();
+    let not = builder.build_not(xor, "not").unwrap();
+    let shl = builder.build_left_shift(not, b, "shl").unwrap();
+    let lshr = builder.build_right_shift(shl, b, false, "lshr").unwrap();
+    let ashr = builder.build_right_shift(lshr, b, true, "ashr").unwrap();
+
+    builder.build_return(Some(&ashr)).unwrap();
+
+    let opcodes: Vec<_> = [and, or, xor, not, shl, lshr, ashr]
+        .iter()
+        .map(|value| value.as_instruction().unwrap().get_opcode())
+        .collect();
+
+    assert_eq!(
+        opcodes,
+        [
+            InstructionOpcode::And,
+            InstructionOpcode::Or,
+            InstructionOpcode::Xor,
+            // There is no dedicated not instruction, it is an xor with all ones
+            InstructionOpcode::Xor,
+            InstructionOpcode::Shl,
+            InstructionOpcode::LShr,
+            InstructionOpcode::AShr,
+        ]
+    );
+    assert!(module.verify().is_ok());
+
+    let ir = function.print_to_string().to_string();
+
+    assert!(ir.contains("%and = and i32 %0, %1"));
+    assert!(ir.contains("%xor = xor i32 %and, %or"));
+    assert!(ir.contains("%not = xor i32 %xor, -1"));
+    assert!(ir.contains("%ashr = ashr i32 %lshr, %1"));
+}
+
+#[test]
+fn test_cast_ops() {
+    let context = Context::create();
+    let module = context.create_module("casts");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let f32_type = context.f32_type();
+    let fn_type = context
+        .void_type()
+        .fn_type(&[i8_type.into(), f32_type.into(), i64_type.into()], false);
+    let fn_value = module.add_function("casts", fn_type, None);
+    let byte = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let float = fn_value.get_nth_param(1).unwrap().into_float_value();
+    let wide = fn_value.get_nth_param(2).unwrap().into_int_value();
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let zext = builder.build_int_z_extend(byte, i32_type, "zext").unwrap();
+    let sext = builder.build_int_s_extend(byte, i32_type, "sext").unwrap();
+    let trunc = builder.build_int_truncate(wide, i32_type, "trunc").unwrap();
+    let bits = builder.build_bit_cast(float, i32_type, "bits").unwrap();
+    let to_int = builder.build_float_to_signed_int(float, i32_type, "to_int").unwrap();
+    let to_float = builder.build_signed_int_to_float(zext, f32_type, "to_float").unwrap();
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = i32_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+    let ptr = builder.build_int_to_ptr(wide, ptr_type, "ptr").unwrap();
+    let addr = builder.build_ptr_to_int(ptr, i64_type, "addr").unwrap();
+
+    builder.build_return(None).unwrap();
+
+    assert_eq!(zext.get_type(), i32_type);
+    assert_eq!(sext.get_type(), i32_type);
+    assert_eq!(trunc.get_type(), i32_type);
+    assert_eq!(bits.into_int_value().get_type(), i32_type);
+    assert_eq!(to_int.get_type(), i32_type);
+    assert_eq!(to_float.get_type(), f32_type);
+    assert_eq!(ptr.get_type(), ptr_type);
+    assert_eq!(addr.get_type(), i64_type);
+    assert!(module.verify().is_ok());
+
+    let ir = fn_value.print_to_string().to_string();
+
+    assert!(ir.contains("%zext = zext i8 %0 to i32"));
+    assert!(ir.contains("%sext = sext i8 %0 to i32"));
+    assert!(ir.contains("%trunc = trunc i64 %2 to i32"));
+    assert!(ir.contains("%bits = bitcast float %1 to i32"));
+    assert!(ir.contains("%to_int = fptosi float %1 to i32"));
+    assert!(ir.contains("%to_float = sitofp i32 %zext to float"));
+    assert!(ir.contains("%ptr = inttoptr i64 %2 to"));
+    assert!(ir.contains("%addr = ptrtoint"));
+}
+
 #[test]
 fn test_atomicrmw() {
     let context = Context::create();
@@ -94,3 +94,62 @@ fn test_get_struct_type() {
 
     assert_eq!(context.get_struct_type("non-existent"), None);
 }
+
+#[test]
+fn test_create_module_and_drop_cleanly() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+
+    assert_eq!(module.get_context(), context);
+    assert_eq!(fn_value.get_type().get_context(), context);
+
+    drop(module);
+    drop(context);
+}
+
+#[test]
+fn test_contexts_on_separate_threads() {
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            std::thread::spawn(move || {
+                let context = Context::create();
+                let module = context.create_module(&format!("my_mod{}", i));
+
+                module.add_function("my_fn", context.i32_type().fn_type(&[], false), None);
+
+                module.get_function("my_fn").is_some()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
+
+#[test]
+fn test_context_moved_to_another_thread() {
+    let context = Context::create();
+    let i64_type = context.i64_type();
+
+    assert_eq!(i64_type.get_bit_width(), 64);
+
+    // Types borrowed from the context must be dropped before it can be moved
+    let handle = std::thread::spawn(move || {
+        {
+            let module = context.create_module("moved");
+
+            module.add_function("my_fn", context.i64_type().fn_type(&[], false), None);
+
+            assert!(module.verify().is_ok());
+        }
+
+        context
+    });
+
+    let context = handle.join().unwrap();
+
+    assert_eq!(context.i64_type().get_bit_width(), 64);
+}
@@ -1,8 +1,11 @@
 use inkwell::context::Context;
-use inkwell::execution_engine::FunctionLookupError;
+use inkwell::execution_engine::{FunctionLookupError, RemoveModuleError};
+use inkwell::memory_buffer::MemoryBuffer;
 use inkwell::targets::{InitializationConfig, Target};
 use inkwell::{AddressSpace, IntPredicate, OptimizationLevel};
 
+use std::sync::atomic::{AtomicI32, Ordering};
+
 type Thunk = unsafe extern "C" fn();
 
 #[test]
@@ -48,6 +51,75 @@ fn test_get_function_address() {
     }
 }
 
+#[test]
+fn test_call_function_by_address() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("by_address");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let fn_value = module.add_function("forty_two", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+    builder.build_return(Some(&i32_type.const_int(42, false))).unwrap();
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    assert_eq!(
+        execution_engine.get_function_address("missing"),
+        Err(FunctionLookupError::FunctionNotFound)
+    );
+
+    let address = execution_engine.get_function_address("forty_two").unwrap();
+
+    assert_ne!(address, 0);
+
+    let forty_two: extern "C" fn() -> i32 = unsafe { std::mem::transmute(address) };
+
+    assert_eq!(forty_two(), 42);
+}
+
+#[test]
+fn test_get_typed_function() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("typed");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("double", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+    let doubled = builder.build_int_add(x, x, "doubled").unwrap();
+
+    builder.build_return(Some(&doubled)).unwrap();
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let double = execution_engine
+            .get_function::<unsafe extern "C" fn(i32) -> i32>("double")
+            .unwrap();
+
+        assert_eq!(double.call(21), 42);
+        assert_eq!(double.call(-4), -8);
+
+        assert_eq!(
+            execution_engine
+                .get_function::<unsafe extern "C" fn(i32) -> i32>("triple")
+                .unwrap_err(),
+            FunctionLookupError::FunctionNotFound
+        );
+    }
+}
+
 #[test]
 fn test_jit_execution_engine() {
     let context = Context::create();
@@ -150,6 +222,246 @@ fn test_interpreter_execution_engine() {
     assert!(module.create_interpreter_execution_engine().is_ok());
 }
 
+#[test]
+fn test_jit_execution_engine_opt_levels() {
+    let context = Context::create();
+
+    for opt_level in [
+        OptimizationLevel::None,
+        OptimizationLevel::Less,
+        OptimizationLevel::Default,
+        OptimizationLevel::Aggressive,
+    ] {
+        let module = context.create_module("main_module");
+
+        assert!(module.create_jit_execution_engine(opt_level).is_ok());
+    }
+}
+
+#[test]
+fn test_interpreter_execution_engine_is_not_jit() {
+    let context = Context::create();
+    let module = context.create_module("main_module");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("func", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None).unwrap();
+
+    // Only the JIT backend can hand out compiled functions
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+    unsafe {
+        assert_eq!(
+            execution_engine.get_function::<Thunk>("func").unwrap_err(),
+            FunctionLookupError::JITNotEnabled
+        );
+    }
+
+    assert_eq!(
+        execution_engine.get_function_value("func"),
+        Err(FunctionLookupError::JITNotEnabled)
+    );
+}
+
+#[test]
+fn test_run_function_with_generic_values() {
+    let context = Context::create();
+    let module = context.create_module("interpreted");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+
+    let add_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let add = module.add_function("add", add_type, None);
+    let basic_block = context.append_basic_block(add, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let lhs = add.get_nth_param(0).unwrap().into_int_value();
+    let rhs = add.get_nth_param(1).unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum").unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    let half_type = f64_type.fn_type(&[f64_type.into()], false);
+    let half = module.add_function("half", half_type, None);
+    let basic_block = context.append_basic_block(half, "entry");
+
+    builder.position_at_end(basic_block);
+
+    let x = half.get_first_param().unwrap().into_float_value();
+    let divided = builder.build_float_div(x, f64_type.const_float(2.), "divided").unwrap();
+
+    builder.build_return(Some(&divided)).unwrap();
+
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+    let a = i32_type.create_generic_value(40, false);
+    let b = i32_type.create_generic_value(2, false);
+    let result = unsafe { execution_engine.run_function(add, &[&a, &b]) };
+
+    assert_eq!(result.as_int(false), 42);
+
+    let neg = i32_type.create_generic_value(-50i64 as u64, true);
+    let result = unsafe { execution_engine.run_function(add, &[&neg, &b]) };
+
+    assert_eq!(result.as_int(true) as i64, -48);
+
+    let x = f64_type.create_generic_value(5.);
+    let result = unsafe { execution_engine.run_function(half, &[&x]) };
+
+    assert_eq!(result.as_float(&f64_type), 2.5);
+}
+
+#[test]
+fn test_add_global_mapping() {
+    static CALLED_WITH: AtomicI32 = AtomicI32::new(0);
+
+    extern "C" fn host_callback(value: i32) {
+        CALLED_WITH.store(value, Ordering::SeqCst);
+    }
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("mapped");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+
+    // Declared, but defined by the host
+    let callback = module.add_function("callback", void_type.fn_type(&[i32_type.into()], false), None);
+    let caller = module.add_function("caller", void_type.fn_type(&[], false), None);
+    let basic_block = context.append_basic_block(caller, "entry");
+
+    builder.position_at_end(basic_block);
+    builder
+        .build_call(callback, &[i32_type.const_int(17, false).into()], "")
+        .unwrap();
+    builder.build_return(None).unwrap();
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    execution_engine.add_global_mapping(&callback, host_callback as extern "C" fn(i32) as usize);
+
+    unsafe {
+        let caller = execution_engine.get_function::<Thunk>("caller").unwrap();
+
+        caller.call();
+    }
+
+    assert_eq!(CALLED_WITH.load(Ordering::SeqCst), 17);
+}
+
+#[test]
+fn test_run_static_constructors_and_destructors() {
+    #[cfg(not(any(feature = "llvm15-0", feature = "llvm16-0", feature = "llvm17-0", feature = "llvm18-0")))]
+    let ir = b"
+        @value = global i32 0
+        @llvm.global_ctors = appending global [1 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 65535, void ()* @ctor, i8* null }]
+        @llvm.global_dtors = appending global [1 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 65535, void ()* @dtor, i8* null }]
+
+        define void @ctor() {
+          store i32 42, i32* @value
+          ret void
+        }
+
+        define void @dtor() {
+          store i32 7, i32* @value
+          ret void
+        }
+
+        define i32 @get() {
+          %v = load i32, i32* @value
+          ret i32 %v
+        }
+    ";
+    #[cfg(any(feature = "llvm15-0", feature = "llvm16-0", feature = "llvm17-0", feature = "llvm18-0"))]
+    let ir = b"
+        @value = global i32 0
+        @llvm.global_ctors = appending global [1 x { i32, ptr, ptr }] [{ i32, ptr, ptr } { i32 65535, ptr @ctor, ptr null }]
+        @llvm.global_dtors = appending global [1 x { i32, ptr, ptr }] [{ i32, ptr, ptr } { i32 65535, ptr @dtor, ptr null }]
+
+        define void @ctor() {
+          store i32 42, ptr @value
+          ret void
+        }
+
+        define void @dtor() {
+          store i32 7, ptr @value
+          ret void
+        }
+
+        define i32 @get() {
+          %v = load i32, ptr @value
+          ret i32 %v
+        }
+    ";
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir, "ctors");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let get = execution_engine
+            .get_function::<unsafe extern "C" fn() -> i32>("get")
+            .unwrap();
+
+        assert_eq!(get.call(), 0);
+
+        execution_engine.run_static_constructors();
+
+        assert_eq!(get.call(), 42);
+
+        execution_engine.run_static_destructors();
+
+        assert_eq!(get.call(), 7);
+    }
+}
+
+#[test]
+fn test_create_and_drop_many_execution_engines() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    for i in 0..50 {
+        let module = context.create_module("ee_owned");
+        let fn_value = module.add_function("func", fn_type, None);
+        let basic_block = context.append_basic_block(fn_value, "entry");
+
+        builder.position_at_end(basic_block);
+        builder.build_return(None).unwrap();
+
+        let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+        let clone = execution_engine.clone();
+
+        unsafe {
+            clone.get_function::<Thunk>("func").unwrap().call();
+        }
+
+        // Drop the engine and module in alternating orders; the underlying
+        // engine (and the module it owns) must only be disposed of once
+        if i % 2 == 0 {
+            drop(execution_engine);
+            drop(clone);
+            drop(module);
+        } else {
+            drop(module);
+            drop(clone);
+            drop(execution_engine);
+        }
+    }
+}
+
 #[test]
 fn test_add_remove_module() {
     let context = Context::create();
@@ -168,6 +480,40 @@ fn test_add_remove_module() {
     assert!(ee.remove_module(&module2).is_ok());
 }
 
+#[test]
+fn test_call_function_from_added_module() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let module = context.create_module("main");
+    let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    let module2 = context.create_module("mod2");
+    let fn_value = module2.add_function("seven", fn_type, None);
+    let basic_block = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(basic_block);
+    builder.build_return(Some(&i32_type.const_int(7, false))).unwrap();
+
+    assert!(ee.add_module(&module2).is_ok());
+
+    // Adding it a second time must not hand ownership over twice
+    assert!(ee.add_module(&module2).is_err());
+
+    unsafe {
+        let seven = ee.get_function::<unsafe extern "C" fn() -> i32>("seven").unwrap();
+
+        assert_eq!(seven.call(), 7);
+    }
+
+    assert!(ee.remove_module(&module2).is_ok());
+    assert_eq!(ee.remove_module(&module2), Err(RemoveModuleError::ModuleNotOwned));
+    assert!(module2.get_function("seven").is_some());
+}
+
 // REVIEW: Global state pollution access tests cause this to pass when run individually
 // but fail when multiple tests are run
 // #[test]
@@ -1,6 +1,6 @@
 use inkwell::context::Context;
 use inkwell::types::{AnyTypeEnum, BasicType};
-use inkwell::values::{BasicValue, InstructionOpcode::*};
+use inkwell::values::{AnyValue, BasicValue, InstructionOpcode::*};
 use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate};
 
 #[test]
@@ -362,6 +362,94 @@ fn test_instructions() {
     assert_eq!(instruction_clone, instruction_clone_copy);
 }
 
+#[test]
+fn test_iterate_block_instructions() {
+    let context = Context::create();
+    let module = context.create_module("testing");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("three", fn_type, None);
+    let basic_block = context.append_basic_block(function, "entry");
+    let arg = function.get_first_param().unwrap().into_int_value();
+
+    assert!(basic_block.get_first_instruction().is_none());
+    assert!(basic_block.get_last_instruction().is_none());
+    assert_eq!(basic_block.get_instructions().count(), 0);
+
+    builder.position_at_end(basic_block);
+
+    let sum = builder.build_int_add(arg, arg, "sum").unwrap();
+    let product = builder.build_int_mul(sum, arg, "product").unwrap();
+
+    builder.build_return(Some(&product)).unwrap();
+
+    let opcodes: Vec<_> = basic_block.get_instructions().map(|i| i.get_opcode()).collect();
+
+    assert_eq!(opcodes, [Add, Mul, Return]);
+
+    // Walking the cursors by hand matches the iterator in both directions
+    let first = basic_block.get_first_instruction().unwrap();
+    let last = basic_block.get_last_instruction().unwrap();
+
+    assert_eq!(Some(first), sum.as_instruction());
+    assert_eq!(first.get_previous_instruction(), None);
+    assert_eq!(last.get_next_instruction(), None);
+
+    let mut forward = vec![first];
+
+    while let Some(next) = forward.last().unwrap().get_next_instruction() {
+        forward.push(next);
+    }
+
+    let mut backward = vec![last];
+
+    while let Some(previous) = backward.last().unwrap().get_previous_instruction() {
+        backward.push(previous);
+    }
+
+    backward.reverse();
+
+    assert_eq!(forward, basic_block.get_instructions().collect::<Vec<_>>());
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn test_add_instruction_opcode_and_operands() {
+    let context = Context::create();
+    let module = context.create_module("testing");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("add_five", fn_type, None);
+    let basic_block = context.append_basic_block(function, "entry");
+    let arg = function.get_first_param().unwrap().into_int_value();
+    let five = i32_type.const_int(5, false);
+
+    builder.position_at_end(basic_block);
+
+    let sum = builder.build_int_add(arg, five, "sum").unwrap();
+    let add = sum.as_instruction().unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    assert_eq!(add.get_opcode(), Add);
+    assert_eq!(add.get_num_operands(), 2);
+    assert_eq!(add.get_operand(0).unwrap().left(), Some(arg.into()));
+    assert_eq!(add.get_operand(1).unwrap().left(), Some(five.into()));
+    assert!(add.get_operand(2).is_none());
+
+    // Rewriting an operand changes what the instruction uses
+    let seven = i32_type.const_int(7, false);
+
+    assert!(add.set_operand(1, seven));
+    assert!(!add.set_operand(2, seven));
+    assert_eq!(add.get_operand(1).unwrap().left(), Some(seven.into()));
+    assert!(five.get_first_use().is_none());
+    assert!(module.verify().is_ok());
+    assert!(function.print_to_string().to_string().contains("%sum = add i32 %0, 7"));
+}
+
 #[llvm_versions(10..)]
 #[test]
 fn test_volatile_atomicrmw_cmpxchg() {
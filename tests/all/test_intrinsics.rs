@@ -49,3 +49,45 @@ fn test_get_decl_va_copy() {
 
     assert_eq!(decl.get_name().to_str().unwrap(), "llvm.va_copy.f32");
 }
+
+#[llvm_versions(9..)]
+#[test]
+fn test_get_decl_sqrt_and_call() {
+    let sqrt = Intrinsic::find("llvm.sqrt").unwrap();
+
+    assert!(sqrt.is_overloaded());
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let f64_type = context.f64_type();
+
+    let decl = sqrt.get_declaration(&module, &[f64_type.into()]).unwrap();
+
+    assert_eq!(decl.get_name().to_str().unwrap(), "llvm.sqrt.f64");
+    assert_ne!(decl.get_intrinsic_id(), 0);
+    assert_eq!(decl.count_basic_blocks(), 0);
+    assert_eq!(module.get_function("llvm.sqrt.f64"), Some(decl));
+
+    // Requesting the same overload again reuses the existing declaration
+    assert_eq!(sqrt.get_declaration(&module, &[f64_type.into()]), Some(decl));
+
+    let fn_type = f64_type.fn_type(&[f64_type.into()], false);
+    let function = module.add_function("my_sqrt", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+
+    let arg = function.get_first_param().unwrap();
+    let call = builder.build_call(decl, &[arg.into()], "root").unwrap();
+    let root = call.try_as_basic_value().left().unwrap();
+
+    builder.build_return(Some(&root)).unwrap();
+
+    assert_eq!(function.get_intrinsic_id(), 0);
+    assert!(module.verify().is_ok());
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("declare double @llvm.sqrt.f64(double"));
+}
@@ -1,9 +1,10 @@
 use inkwell::context::Context;
+use inkwell::execution_engine::ExecutionEngineError;
 use inkwell::memory_buffer::MemoryBuffer;
-use inkwell::module::Module;
-use inkwell::targets::{Target, TargetTriple};
+use inkwell::module::{Linkage, Module};
+use inkwell::targets::{InitializationConfig, Target, TargetTriple};
 use inkwell::values::AnyValue;
-use inkwell::OptimizationLevel;
+use inkwell::{AddressSpace, OptimizationLevel};
 
 use std::env::temp_dir;
 use std::fs::{remove_file, File};
@@ -107,6 +108,231 @@ fn test_get_function() {
 
     let functions: Vec<_> = module.get_functions().collect();
     assert_eq!(functions, vec![function_1, function_2]);
+
+    let function_3 = module.add_function("function_3", function_2_type, None);
+
+    let functions: Vec<_> = module.get_functions().collect();
+    assert_eq!(functions, vec![function_1, function_2, function_3]);
+
+    let names: Vec<_> = module
+        .get_functions()
+        .map(|f| f.get_name().to_str().unwrap().to_owned())
+        .collect();
+    assert_eq!(names, ["function_1", "function_2", "function_3"]);
+}
+
+#[test]
+fn test_walk_functions_forward_and_backward() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    for name in ["a", "b", "c", "d"] {
+        module.add_function(name, fn_type, None);
+    }
+
+    let mut forward = Vec::new();
+    let mut cursor = module.get_first_function();
+
+    while let Some(function) = cursor {
+        forward.push(function);
+        cursor = function.get_next_function();
+    }
+
+    let mut backward = Vec::new();
+    let mut cursor = module.get_last_function();
+
+    while let Some(function) = cursor {
+        backward.push(function);
+        cursor = function.get_previous_function();
+    }
+
+    let names: Vec<_> = forward.iter().map(|f| f.get_name().to_str().unwrap()).collect();
+
+    assert_eq!(names, ["a", "b", "c", "d"]);
+    assert_eq!(forward, module.get_functions().collect::<Vec<_>>());
+
+    backward.reverse();
+
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn test_delete_function_and_basic_blocks() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    let function = module.add_function("regenerated", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let dead = context.append_basic_block(function, "dead");
+
+    builder.position_at_end(entry);
+    builder.build_return(None).unwrap();
+    builder.position_at_end(dead);
+    builder.build_return(None).unwrap();
+
+    unsafe {
+        assert!(dead.delete().is_ok());
+    }
+
+    assert_eq!(function.get_basic_blocks(), vec![entry]);
+    assert!(function.verify(false));
+
+    assert!(entry.remove_from_function().is_ok());
+    assert!(function.get_basic_blocks().is_empty());
+
+    unsafe {
+        // Deletion requires a parent, so an orphaned block is rejected
+        assert!(entry.delete().is_err());
+    }
+
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None).unwrap();
+
+    assert_eq!(module.get_function("regenerated"), Some(function));
+
+    unsafe {
+        function.delete();
+    }
+
+    assert!(module.get_function("regenerated").is_none());
+    assert!(module.get_first_function().is_none());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_get_global() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    assert!(module.get_global("g").is_none());
+
+    let global = module.add_global(i32_type, None, "g");
+
+    module.add_function("f", fn_type, None);
+
+    assert_eq!(module.get_global("g").unwrap(), global);
+    assert!(module.get_global("h").is_none());
+
+    // Functions are global values too, but are not global variables
+    assert!(module.get_global("f").is_none());
+}
+
+#[test]
+fn test_add_global_declaration() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+
+    // A global without an initializer is an external declaration
+    let declared = module.add_global(i32_type, None, "runtime_flag");
+
+    assert!(declared.is_declaration());
+    assert_eq!(declared.get_linkage(), Linkage::External);
+    assert!(declared.get_initializer().is_none());
+    assert_eq!(module.get_global("runtime_flag"), Some(declared));
+
+    // ...until it is given one
+    let defined = module.add_global(i32_type, None, "defined_flag");
+
+    defined.set_initializer(&i32_type.const_zero());
+
+    assert!(!defined.is_declaration());
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@runtime_flag = external global i32\n"));
+    assert!(ir.contains("@defined_flag = global i32 0\n"));
+}
+
+#[test]
+fn test_get_context_types_usable_in_module() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let module_context = module.get_context();
+
+    assert_eq!(module_context, context);
+
+    let i64_type = module_context.i64_type();
+    let fn_type = i64_type.fn_type(&[i64_type.into()], false);
+    let fn_value = module.add_function("identity", fn_type, None);
+    let builder = module_context.create_builder();
+
+    builder.position_at_end(module_context.append_basic_block(fn_value, "entry"));
+    builder
+        .build_return(Some(&fn_value.get_first_param().unwrap()))
+        .unwrap();
+
+    assert_eq!(i64_type, context.i64_type());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_names_with_interior_null_bytes() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let i32_type = context.i32_type();
+
+    let err = module.try_add_function("f\0oo", fn_type, None).unwrap_err();
+
+    assert_eq!(err.nul_position(), 1);
+    assert!(module.try_add_global(i32_type, None, "g\0lobal").is_err());
+    assert!(module.get_first_function().is_none());
+    assert!(module.get_first_global().is_none());
+
+    // A single trailing null byte is just a terminator
+    let function = module.try_add_function("f\0", fn_type, None).unwrap();
+    let global = module.try_add_global(i32_type, None, "g").unwrap();
+
+    assert_eq!(function.get_name().to_str(), Ok("f"));
+    assert_eq!(global.get_name().to_str(), Ok("g"));
+    assert_eq!(module.try_get_function("f"), Ok(Some(function)));
+    assert_eq!(module.try_get_global("g\0"), Ok(Some(global)));
+    assert!(module.try_get_function("f\0anything").is_err());
+    assert!(module.try_get_global("g\0anything").is_err());
+
+    assert!(TargetTriple::try_create("x86_64-pc-linux-gnu\0ignored").is_err());
+
+    let triple = TargetTriple::try_create("x86_64-pc-linux-gnu").unwrap();
+
+    module.set_triple(&triple);
+
+    assert_eq!(module.get_triple(), triple);
+}
+
+#[llvm_versions(7..)]
+#[test]
+fn test_inline_assembly() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    assert_eq!(module.get_inline_assembly().to_str(), Ok(""));
+
+    module.set_inline_assembly(".globl my_stub\nmy_stub:\n  ret");
+
+    assert_eq!(
+        module.get_inline_assembly().to_str(),
+        Ok(".globl my_stub\nmy_stub:\n  ret\n")
+    );
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.starts_with(
+        "; ModuleID = 'my_module'\nsource_filename = \"my_module\"\n\nmodule asm \".globl my_stub\"\nmodule asm \"my_stub:\"\nmodule asm \"  ret\"\n"
+    ));
+
+    // Setting replaces rather than appends
+    module.set_inline_assembly("nop");
+
+    assert_eq!(module.get_inline_assembly().to_str(), Ok("nop\n"));
 }
 
 #[test]
@@ -116,6 +342,30 @@ fn test_module_owned_data_layout_disposed_safely() {
     context.create_module("test");
 }
 
+#[test]
+fn test_create_and_drop_many_modules() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    for i in 0..100 {
+        let module = context.create_module(&format!("module_{}", i));
+
+        module.add_function("my_fn", fn_type, None);
+
+        // Every other module hands ownership over to an execution engine,
+        // which must then be the only one to dispose of it
+        if i % 2 == 0 {
+            let execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+            drop(module);
+            drop(execution_engine);
+        }
+    }
+}
+
 #[test]
 fn test_write_and_load_memory_buffer() {
     let context = Context::create();
@@ -146,6 +396,74 @@ fn test_write_and_load_memory_buffer() {
     assert!(object_file.is_err());
 }
 
+#[test]
+fn test_write_bitcode_to_memory_matches_path() {
+    let mut path = temp_dir();
+
+    path.push("temp_memory.bc");
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    module.add_function("my_fn", fn_type, None);
+
+    assert!(module.write_bitcode_to_path(&path));
+
+    let mut contents = Vec::new();
+    let mut file = File::open(&path).expect("Could not open temp file");
+
+    file.read_to_end(&mut contents).expect("Unable to verify written file");
+
+    let memory_buffer = module.write_bitcode_to_memory();
+
+    assert_eq!(memory_buffer.get_size(), contents.len());
+    assert_eq!(memory_buffer.as_slice(), &contents[..]);
+
+    remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_memory_buffer_from_file() {
+    let mut path = temp_dir();
+
+    path.push("temp_memory_buffer.txt");
+
+    std::fs::write(&path, b"some bytes\0and more").unwrap();
+
+    let memory_buffer = MemoryBuffer::create_from_file(&path).unwrap();
+
+    assert_eq!(memory_buffer.get_size(), 19);
+    assert_eq!(memory_buffer.as_slice(), b"some bytes\0and more");
+
+    remove_file(&path).unwrap();
+
+    assert!(MemoryBuffer::create_from_file(&path).is_err());
+
+    let empty_buffer = MemoryBuffer::create_from_memory_range_copy(b"", "empty");
+
+    assert_eq!(empty_buffer.get_size(), 0);
+    assert!(empty_buffer.as_slice().is_empty());
+}
+
+#[test]
+fn test_create_module_from_textual_ir() {
+    let context = Context::create();
+    let ir = b"define i32 @add(i32 %a, i32 %b) {\nentry:\n  %sum = add i32 %a, %b\n  ret i32 %sum\n}\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir, "my_ir");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert_eq!(module.get_context(), context);
+    assert!(module.verify().is_ok());
+
+    let add = module.get_function("add").unwrap();
+
+    assert_eq!(add.count_params(), 2);
+    assert_eq!(add.count_basic_blocks(), 1);
+    assert!(module.get_function("sub").is_none());
+}
+
 #[test]
 fn test_garbage_ir_fails_create_module_from_ir() {
     let context = Context::create();
@@ -179,6 +497,44 @@ fn test_get_struct_type() {
     assert_eq!(module.get_struct_type("foo").unwrap(), opaque);
 }
 
+#[test]
+fn test_get_struct_type_set_body_recursively() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i64_type = context.i64_type();
+
+    context.opaque_struct_type("Node");
+
+    // Look the struct up by name to define its body, which refers to the struct itself
+    let node_type = module.get_struct_type("Node").unwrap();
+
+    assert!(node_type.is_opaque());
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let next_type = node_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let next_type = context.ptr_type(AddressSpace::default());
+
+    assert!(node_type.set_body(&[i64_type.into(), next_type.into()], false));
+
+    let node_type = module.get_struct_type("Node").unwrap();
+
+    assert!(!node_type.is_opaque());
+    assert_eq!(node_type.get_field_types(), &[i64_type.into(), next_type.into()]);
+    assert_eq!(node_type.get_name().map(|name| name.to_str()), Some(Ok("Node")));
+    assert!(module.get_struct_type("Missing").is_none());
+}
+
 #[test]
 fn test_get_struct_type_global_context() {
     unsafe {
@@ -274,20 +630,32 @@ fn test_parse_from_path() {
 
     builder.position_at_end(basic_block);
     builder.build_return(None).unwrap();
+    module.add_function("g", fn_type, None);
 
     assert!(module.verify().is_ok(), "3");
 
-    // FIXME: Wasn't able to test success case. Got "invalid bitcode signature"
     let mut temp_path = temp_dir();
 
     temp_path.push("module.bc");
 
     module.write_bitcode_to_path(&temp_path);
 
-    let module3_result = Module::parse_bitcode_from_path(&temp_path, &context);
+    let module3 = Module::parse_bitcode_from_path(&temp_path, &context).unwrap();
+
+    assert_eq!(module3.get_context(), context);
 
-    assert!(module3_result.is_ok());
-    assert_eq!(module3_result.unwrap().get_context(), context);
+    let fn_names: Vec<_> = module3
+        .get_functions()
+        .map(|f| f.get_name().to_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(fn_names, ["f", "g"]);
+    assert_eq!(
+        module3.get_function("f").unwrap().print_to_string(),
+        f.print_to_string()
+    );
+
+    remove_file(&temp_path).unwrap();
 }
 
 #[test]
@@ -307,6 +675,66 @@ fn test_clone() {
 
     assert_ne!(module, module2);
     assert_eq!(module.print_to_string(), module2.print_to_string());
+    assert_eq!(module2.get_context(), context);
+
+    // Mutating the clone leaves the original untouched
+    module2.add_function("g", fn_type, None);
+
+    assert!(module2.get_function("g").is_some());
+    assert!(module.get_function("g").is_none());
+    assert_ne!(module.print_to_string(), module2.print_to_string());
+
+    // The functions of the clone are distinct from the original's
+    assert_ne!(module2.get_function("f"), Some(f));
+
+    drop(module2);
+
+    assert_eq!(module.get_function("f"), Some(f));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_verify() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let f = module.add_function("f", fn_type, None);
+    let basic_block = context.append_basic_block(f, "entry");
+
+    // A basic block without a terminator is invalid
+    let err = module.verify().unwrap_err();
+    let err = err.to_str().unwrap();
+
+    assert!(!err.is_empty());
+    assert!(err.contains("terminator"), "{}", err);
+
+    let builder = context.create_builder();
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_print_to_string() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let f = module.add_function("my_fn", fn_type, None);
+    let basic_block = context.append_basic_block(f, "entry");
+    let builder = context.create_builder();
+
+    builder.position_at_end(basic_block);
+    builder.build_return(None).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("; ModuleID = 'mod'"));
+    assert!(ir.contains("define void @my_fn()"));
+    assert_eq!(ir, module.to_string());
 }
 
 #[test]
@@ -331,9 +759,19 @@ fn test_print_to_file() {
 
     let mut temp_path = temp_dir();
 
-    temp_path.push("module");
+    temp_path.push("module.ll");
 
     assert!(module.print_to_file(&temp_path).is_ok());
+
+    let mut contents = String::new();
+    let mut file = File::open(&temp_path).expect("Could not open temp file");
+
+    file.read_to_string(&mut contents).expect("Unable to read written file");
+
+    assert!(contents.contains("define void @f()"));
+    assert_eq!(contents, module.to_string());
+
+    remove_file(&temp_path).unwrap();
 }
 
 #[test]
@@ -362,6 +800,13 @@ fn test_get_set_target() {
         assert_eq!(module.get_source_file_name().to_str(), Ok("foo.rs"));
         assert_eq!(module.get_name().to_str(), Ok("mod2"));
     }
+
+    let ir = b"target triple = \"x86_64-pc-linux-gnu\"\n";
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir, "parsed_mod");
+    let parsed_module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert_eq!(parsed_module.get_name().to_str(), Ok("parsed_mod"));
+    assert_eq!(parsed_module.get_triple(), triple);
 }
 
 #[test]
@@ -396,6 +841,7 @@ fn test_linking_modules() {
     assert_eq!(module.get_function("f"), Some(fn_val));
 
     // fn_val2 is no longer the same instance of f2
+    assert!(module.get_function("f2").is_some());
     assert_ne!(module.get_function("f2"), Some(fn_val2));
 
     let _execution_engine = module
@@ -474,6 +920,32 @@ fn test_metadata_flags() {
     }
 }
 
+#[llvm_versions(7..)]
+#[test]
+fn test_debug_info_version_flag() {
+    use inkwell::module::FlagBehavior;
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let debug_info_version = context.i32_type().const_int(3, false);
+
+    module.add_basic_value_flag("Debug Info Version", FlagBehavior::Warning, debug_info_version);
+    module.add_basic_value_flag(
+        "Dwarf Version",
+        FlagBehavior::Override,
+        context.i32_type().const_int(4, false),
+    );
+
+    assert!(module.get_flag("Debug Info Version").is_some());
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("!llvm.module.flags = !{!0, !1}"));
+    assert!(ir.contains("!0 = !{i32 2, !\"Debug Info Version\", i32 3}"));
+    assert!(ir.contains("!1 = !{i32 4, !\"Dwarf Version\", i32 4}"));
+}
+
 #[test]
 fn test_double_ee_from_same_module() {
     let context = Context::create();
@@ -491,7 +963,13 @@ fn test_double_ee_from_same_module() {
         .create_execution_engine()
         .expect("Could not create Execution Engine");
 
-    assert!(module.create_execution_engine().is_err());
+    let err = module.create_execution_engine().unwrap_err();
+
+    assert_eq!(err, ExecutionEngineError::ModuleAlreadyOwned);
+    assert_eq!(
+        err.to_string(),
+        "ExecutionEngineError(This module is already owned by an ExecutionEngine)"
+    );
 
     let module2 = module.clone();
 
@@ -499,7 +977,10 @@ fn test_double_ee_from_same_module() {
         .create_jit_execution_engine(OptimizationLevel::None)
         .expect("Could not create Execution Engine");
 
-    assert!(module.create_jit_execution_engine(OptimizationLevel::None).is_err());
+    assert_eq!(
+        module.create_jit_execution_engine(OptimizationLevel::None).unwrap_err(),
+        ExecutionEngineError::ModuleAlreadyOwned
+    );
 
     let module3 = module.clone();
 
@@ -507,5 +988,8 @@ fn test_double_ee_from_same_module() {
         .create_interpreter_execution_engine()
         .expect("Could not create Execution Engine");
 
-    assert!(module.create_interpreter_execution_engine().is_err());
+    assert_eq!(
+        module.create_interpreter_execution_engine().unwrap_err(),
+        ExecutionEngineError::ModuleAlreadyOwned
+    );
 }
@@ -1,5 +1,7 @@
 use inkwell::context::Context;
+use inkwell::module::{Linkage, Module};
 use inkwell::passes::{PassManager, PassManagerBuilder, PassRegistry};
+use inkwell::values::{AnyValue, FunctionValue};
 
 #[llvm_versions(13..)]
 use inkwell::passes::PassBuilderOptions;
@@ -221,6 +223,186 @@ fn test_pass_manager_builder() {
     assert!(module_pass_manager.run_on(&module));
 }
 
+#[test]
+fn test_pass_manager_builder_o2_removes_redundancy() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("redundant", fn_type, None);
+    let x = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let y = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let product1 = builder.build_int_mul(x, y, "product1").unwrap();
+    let product2 = builder.build_int_mul(y, x, "product2").unwrap();
+    let diff = builder.build_int_sub(product1, product2, "diff").unwrap();
+
+    builder.build_return(Some(&diff)).unwrap();
+
+    let pass_manager_builder = PassManagerBuilder::create();
+
+    pass_manager_builder.set_optimization_level(OptimizationLevel::Default);
+
+    let fn_pass_manager = PassManager::create(&module);
+
+    pass_manager_builder.populate_function_pass_manager(&fn_pass_manager);
+
+    fn_pass_manager.initialize();
+
+    assert!(fn_pass_manager.run_on(&fn_value));
+
+    fn_pass_manager.finalize();
+
+    let ir = fn_value.print_to_string().to_string();
+
+    assert!(!ir.contains("%product2"));
+    assert!(ir.contains("ret i32 0"));
+}
+
+#[test]
+fn test_function_passes_remove_redundancy() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("redundant", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let x = fn_value.get_nth_param(0).unwrap().into_int_value();
+    let y = fn_value.get_nth_param(1).unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    // (x + y) - (x + y) is always zero
+    let sum1 = builder.build_int_add(x, y, "sum1").unwrap();
+    let sum2 = builder.build_int_add(x, y, "sum2").unwrap();
+    let diff = builder.build_int_sub(sum1, sum2, "diff").unwrap();
+
+    builder.build_return(Some(&diff)).unwrap();
+
+    let fn_pass_manager = PassManager::create(&module);
+
+    fn_pass_manager.add_promote_memory_to_register_pass();
+    fn_pass_manager.add_instruction_combining_pass();
+    fn_pass_manager.add_reassociate_pass();
+    fn_pass_manager.add_gvn_pass();
+    fn_pass_manager.add_cfg_simplification_pass();
+
+    let before = fn_value.print_to_string().to_string();
+
+    fn_pass_manager.initialize();
+    fn_pass_manager.run_on(&fn_value);
+    fn_pass_manager.finalize();
+
+    let after = fn_value.print_to_string().to_string();
+
+    assert_ne!(before, after);
+    assert!(before.contains("%sum2 = add i32"));
+    assert!(!after.contains("add i32"));
+    assert!(after.contains("ret i32 0"));
+}
+
+#[test]
+fn test_run_on_function_reports_modification() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+
+    // identity(x) is already optimal, whereas twice(x) adds a zero it doesn't need
+    let identity = module.add_function("identity", fn_type, None);
+    let twice = module.add_function("twice", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(identity, "entry"));
+    builder
+        .build_return(Some(&identity.get_first_param().unwrap()))
+        .unwrap();
+
+    builder.position_at_end(context.append_basic_block(twice, "entry"));
+
+    let x = twice.get_first_param().unwrap().into_int_value();
+    let add = builder.build_int_add(x, i32_type.const_zero(), "add").unwrap();
+
+    builder.build_return(Some(&add)).unwrap();
+
+    let fn_pass_manager = PassManager::create(&module);
+
+    fn_pass_manager.add_instruction_combining_pass();
+
+    fn_pass_manager.initialize();
+
+    assert!(!fn_pass_manager.run_on(&identity));
+    assert!(fn_pass_manager.run_on(&twice));
+    assert!(!fn_pass_manager.run_on(&twice));
+
+    fn_pass_manager.finalize();
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_module_passes_inline_callee() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let callee = module.add_function("add_one", fn_type, Some(Linkage::Internal));
+    let caller = module.add_function("caller", fn_type, None);
+
+    builder.position_at_end(context.append_basic_block(callee, "entry"));
+
+    let x = callee.get_first_param().unwrap().into_int_value();
+    let add = builder.build_int_add(x, i32_type.const_int(1, false), "add").unwrap();
+
+    builder.build_return(Some(&add)).unwrap();
+    builder.position_at_end(context.append_basic_block(caller, "entry"));
+
+    let x = caller.get_first_param().unwrap();
+    let call = builder.build_call(callee, &[x.into()], "call").unwrap();
+
+    builder
+        .build_return(Some(&call.try_as_basic_value().left().unwrap()))
+        .unwrap();
+
+    assert!(caller.print_to_string().to_string().contains("call i32 @add_one"));
+
+    let module_pass_manager = PassManager::create(());
+
+    module_pass_manager.add_function_inlining_pass();
+    module_pass_manager.add_global_dce_pass();
+    module_pass_manager.add_dead_arg_elimination_pass();
+
+    assert!(module_pass_manager.run_on(&module));
+    assert!(!caller.print_to_string().to_string().contains("call i32"));
+    assert!(module.get_function("add_one").is_none());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_create_and_drop_many_pass_managers() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    for _ in 0..1000 {
+        let fn_pass_manager: PassManager<FunctionValue> = PassManager::create(&module);
+        let module_pass_manager: PassManager<Module> = PassManager::create(());
+
+        fn_pass_manager.add_instruction_combining_pass();
+        module_pass_manager.add_global_dce_pass();
+
+        drop(module_pass_manager);
+        drop(fn_pass_manager);
+    }
+
+    // The module must still be usable once all of its function pass managers are gone
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_pass_registry() {
     let pass_registry = PassRegistry::get_global();
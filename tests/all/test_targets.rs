@@ -178,6 +178,75 @@ fn test_target_and_target_machine() {
     }
 }
 
+#[llvm_versions(7..)]
+#[test]
+fn test_native_target_machine_data_layout() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            TargetMachine::get_host_cpu_name().to_str().unwrap(),
+            TargetMachine::get_host_cpu_features().to_str().unwrap(),
+            OptimizationLevel::Default,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    assert_eq!(target_machine.get_triple(), triple);
+
+    let target_data = target_machine.get_target_data();
+    let data_layout = target_data.get_data_layout();
+
+    assert!(!data_layout.as_str().to_bytes().is_empty());
+    assert_eq!(
+        target_data.get_pointer_byte_size(None) as usize,
+        std::mem::size_of::<usize>()
+    );
+
+    let context = Context::create();
+    let module = context.create_module("native");
+
+    module.set_triple(&triple);
+    module.set_data_layout(&data_layout);
+
+    assert_eq!(module.get_triple(), triple);
+    assert_eq!(*module.get_data_layout(), data_layout);
+}
+
+#[test]
+fn test_initialize_all_cross_targets() {
+    Target::initialize_all(&InitializationConfig::default());
+
+    let x86_target = Target::from_triple(&TargetTriple::create("x86_64-unknown-linux-gnu")).unwrap();
+    let aarch64_target = Target::from_triple(&TargetTriple::create("aarch64-unknown-linux-gnu")).unwrap();
+
+    assert_eq!(x86_target.get_name().to_str(), Ok("x86-64"));
+    assert_eq!(aarch64_target.get_name().to_str(), Ok("aarch64"));
+    assert!(aarch64_target.has_target_machine());
+
+    // A cross target machine can be created without the host being that architecture
+    let target_machine = aarch64_target
+        .create_target_machine(
+            &TargetTriple::create("aarch64-unknown-linux-gnu"),
+            "generic",
+            "",
+            OptimizationLevel::Default,
+            RelocMode::Default,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    assert_eq!(target_machine.get_target_data().get_pointer_byte_size(None), 8);
+    assert_eq!(
+        target_machine.get_target_data().get_byte_ordering(),
+        ByteOrdering::LittleEndian
+    );
+}
+
 #[test]
 fn test_default_triple() {
     let default_triple = TargetMachine::get_default_triple();
@@ -218,6 +287,10 @@ fn test_target_data() {
     let module = context.create_module("sum");
     let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
     let target_data = execution_engine.get_target_data();
+    let pointer_size = target_data.get_pointer_byte_size(None);
+
+    assert!(pointer_size == 4 || pointer_size == 8);
+    assert_eq!(pointer_size as usize, std::mem::size_of::<usize>());
 
     let data_layout = target_data.get_data_layout();
 
@@ -330,6 +403,102 @@ fn test_target_data() {
     TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
 }
 
+const X86_64_DATA_LAYOUT: &str = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-f80:128-n8:16:32:64-S128";
+
+#[test]
+fn test_data_layout_from_string() {
+    let context = Context::create();
+    let module = context.create_module("layout");
+    let target_data = TargetData::create(X86_64_DATA_LAYOUT);
+    let data_layout = target_data.get_data_layout();
+
+    assert_eq!(data_layout.as_str().to_str(), Ok(X86_64_DATA_LAYOUT));
+    assert_eq!(module.get_data_layout().as_str().to_str(), Ok(""));
+
+    module.set_data_layout(&data_layout);
+
+    assert_eq!(*module.get_data_layout(), data_layout);
+    assert_eq!(module.get_data_layout().as_str().to_str(), Ok(X86_64_DATA_LAYOUT));
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains(&format!("target datalayout = \"{}\"", X86_64_DATA_LAYOUT)));
+
+    // The module keeps its own copy of the layout after the target data is gone
+    drop(data_layout);
+    drop(target_data);
+
+    assert_eq!(module.get_data_layout().as_str().to_str(), Ok(X86_64_DATA_LAYOUT));
+}
+
+#[test]
+fn test_data_layout_type_sizes() {
+    let context = Context::create();
+    let target_data = TargetData::create(X86_64_DATA_LAYOUT);
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let struct_type = context.struct_type(&[i32_type.into(), i64_type.into()], false);
+    let packed_type = context.struct_type(&[i32_type.into(), i64_type.into()], true);
+
+    // The i64 field is aligned to 8 bytes, leaving 4 bytes of padding after the i32
+    assert_eq!(target_data.get_abi_size(&struct_type), 16);
+    assert_eq!(target_data.get_store_size(&struct_type), 16);
+    assert_eq!(target_data.get_bit_size(&struct_type), 128);
+    assert_eq!(target_data.get_abi_alignment(&struct_type), 8);
+    assert_eq!(target_data.get_preferred_alignment(&struct_type), 8);
+
+    assert_eq!(target_data.get_abi_size(&packed_type), 12);
+    assert_eq!(target_data.get_abi_alignment(&packed_type), 1);
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = i32_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+
+    assert_eq!(target_data.get_pointer_byte_size(None), 8);
+    assert_eq!(
+        target_data.get_abi_size(&ptr_type),
+        target_data.get_pointer_byte_size(None) as u64
+    );
+    assert_eq!(
+        target_data.get_bit_size(&ptr_type),
+        target_data.get_pointer_byte_size(None) as u64 * 8
+    );
+}
+
+#[test]
+fn test_data_layout_byte_order_and_offsets() {
+    let context = Context::create();
+    let little_endian = TargetData::create(X86_64_DATA_LAYOUT);
+    let big_endian = TargetData::create("E-m:e-i64:64-n32:64-S128");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let struct_type = context.struct_type(&[i32_type.into(), i64_type.into()], false);
+
+    assert_eq!(little_endian.get_byte_ordering(), ByteOrdering::LittleEndian);
+    assert_eq!(big_endian.get_byte_ordering(), ByteOrdering::BigEndian);
+
+    assert_eq!(little_endian.offset_of_element(&struct_type, 0), Some(0));
+    assert_eq!(little_endian.offset_of_element(&struct_type, 1), Some(8));
+    assert!(little_endian.offset_of_element(&struct_type, 2).is_none());
+
+    // Offsets within the padding belong to the preceding field
+    assert_eq!(little_endian.element_at_offset(&struct_type, 0), 0);
+    assert_eq!(little_endian.element_at_offset(&struct_type, 6), 0);
+    assert_eq!(little_endian.element_at_offset(&struct_type, 8), 1);
+    assert_eq!(little_endian.element_at_offset(&struct_type, 15), 1);
+}
+
 #[test]
 fn test_ptr_sized_int() {
     Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
@@ -409,6 +578,61 @@ fn test_write_target_machine_to_file() {
     remove_file(&path).unwrap();
 }
 
+#[test]
+fn test_write_object_file() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(&triple).unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &triple,
+            "generic",
+            "",
+            OptimizationLevel::None,
+            RelocMode::PIC,
+            CodeModel::Default,
+        )
+        .unwrap();
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let fn_value = module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+    builder.build_return(None).unwrap();
+
+    let mut path = temp_dir();
+
+    path.push("temp_object.o");
+
+    assert!(target_machine.write_to_file(&module, FileType::Object, &path).is_ok());
+
+    let mut contents = Vec::new();
+    let mut file = File::open(&path).expect("Could not open temp file");
+
+    file.read_to_end(&mut contents).expect("Unable to verify written file");
+    remove_file(&path).unwrap();
+
+    let is_elf = contents.starts_with(b"\x7fELF");
+    let is_mach_o = contents.starts_with(&[0xcf, 0xfa, 0xed, 0xfe]);
+    let is_coff = contents.starts_with(&[0x64, 0x86]) || contents.starts_with(&[0x64, 0xaa]);
+
+    assert!(is_elf || is_mach_o || is_coff, "Unknown object file magic");
+
+    // Failing to emit reports LLVM's error rather than panicking
+    let mut bad_path = temp_dir();
+
+    bad_path.push("no_such_directory");
+    bad_path.push("temp_object.o");
+
+    assert!(!target_machine
+        .write_to_file(&module, FileType::Object, &bad_path)
+        .unwrap_err()
+        .to_string()
+        .is_empty());
+}
+
 #[test]
 fn test_write_target_machine_to_memory_buffer() {
     Target::initialize_x86(&InitializationConfig::default());
@@ -1,5 +1,5 @@
 use inkwell::context::Context;
-use inkwell::types::BasicType;
+use inkwell::types::{AnyType, AnyTypeEnum, BasicType, BasicTypeEnum, FloatTypeKind};
 use inkwell::values::AnyValue;
 use inkwell::AddressSpace;
 
@@ -118,6 +118,71 @@ fn test_struct_type() {
     assert!(fields_changed_struct.get_field_type_at_index(3).is_none());
 }
 
+#[test]
+fn test_struct_field_type_at_index() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+    let struct_type = context.struct_type(&[i32_type.into(), f32_type.into()], false);
+
+    assert_eq!(struct_type.get_field_type_at_index(0), Some(i32_type.into()));
+    assert_eq!(struct_type.get_field_type_at_index(1), Some(f32_type.into()));
+    assert!(struct_type.get_field_type_at_index(2).is_none());
+    assert!(struct_type.get_field_type_at_index(u32::MAX).is_none());
+
+    let empty_struct = context.struct_type(&[], false);
+
+    assert!(empty_struct.get_field_type_at_index(0).is_none());
+}
+
+#[test]
+fn test_packed_and_recursive_struct_types() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let i8_type = context.i8_type();
+    let packed = context.struct_type(&[i32_type.into(), i8_type.into()], true);
+
+    assert!(packed.is_packed());
+    assert_eq!(packed.count_fields(), 2);
+    assert_eq!(packed.get_field_types(), &[i32_type.into(), i8_type.into()]);
+    assert_eq!(packed.print_to_string().to_str(), Ok("<{ i32, i8 }>"));
+
+    // A linked list node has to be declared before it can point to itself
+    let node = context.opaque_struct_type("node");
+
+    assert!(node.is_opaque());
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let (next_type, node_ir) = (node.ptr_type(AddressSpace::default()), "%node = type { i32, %node* }");
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let (next_type, node_ir) = (context.ptr_type(AddressSpace::default()), "%node = type { i32, ptr }");
+
+    assert!(node.set_body(&[i32_type.into(), next_type.into()], false));
+    assert!(!node.is_opaque());
+    assert!(!node.is_packed());
+    assert_eq!(node.count_fields(), 2);
+    assert_eq!(node.get_field_types(), &[i32_type.into(), next_type.into()]);
+    assert_eq!(node.print_to_string().to_str(), Ok(node_ir));
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert_eq!(next_type.get_element_type().into_struct_type(), node);
+}
+
 #[test]
 fn test_function_type() {
     let context = Context::create();
@@ -141,6 +206,40 @@ fn test_function_type() {
     assert_eq!(fn_type.get_context(), context);
 }
 
+#[test]
+fn test_function_type_signatures() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let binary_fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+
+    assert_eq!(binary_fn_type.print_to_string().to_str(), Ok("i32 (i32, i32)"));
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let (ptr_type, printf_signature) = (context.i8_type().ptr_type(AddressSpace::default()), "i32 (i8*, ...)");
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let (ptr_type, printf_signature) = (context.ptr_type(AddressSpace::default()), "i32 (ptr, ...)");
+    let printf_fn_type = i32_type.fn_type(&[ptr_type.into()], true);
+
+    assert!(printf_fn_type.is_var_arg());
+    assert_eq!(printf_fn_type.count_param_types(), 1);
+    assert_eq!(printf_fn_type.print_to_string().to_str(), Ok(printf_signature));
+
+    let void_fn_type = context.void_type().fn_type(&[], false);
+
+    assert_eq!(void_fn_type.print_to_string().to_str(), Ok("void ()"));
+    assert!(void_fn_type.get_return_type().is_none());
+}
+
 #[test]
 fn test_sized_types() {
     unsafe { Context::get_global(sized_types) }
@@ -421,6 +520,54 @@ fn test_const_zero() {
     );
 }
 
+#[test]
+fn test_int_type_bit_widths() {
+    let context = Context::create();
+
+    assert_eq!(context.bool_type().get_bit_width(), 1);
+    assert_eq!(context.i8_type().get_bit_width(), 8);
+    assert_eq!(context.i16_type().get_bit_width(), 16);
+    assert_eq!(context.i32_type().get_bit_width(), 32);
+    assert_eq!(context.i64_type().get_bit_width(), 64);
+    assert_eq!(context.i128_type().get_bit_width(), 128);
+    assert_eq!(context.custom_width_int_type(1).get_bit_width(), 1);
+    assert_eq!(context.custom_width_int_type(7).get_bit_width(), 7);
+    assert_eq!(context.custom_width_int_type(256).get_bit_width(), 256);
+
+    // Integer types are uniqued by width within a context
+    assert_eq!(context.custom_width_int_type(32), context.i32_type());
+    assert_eq!(context.custom_width_int_type(1), context.bool_type());
+}
+
+#[test]
+fn test_float_type_kinds() {
+    let context = Context::create();
+
+    assert_eq!(context.f16_type().get_kind(), FloatTypeKind::Half);
+    assert_eq!(context.f32_type().get_kind(), FloatTypeKind::Float);
+    assert_eq!(context.f64_type().get_kind(), FloatTypeKind::Double);
+    assert_eq!(context.x86_f80_type().get_kind(), FloatTypeKind::X86FP80);
+    assert_eq!(context.f128_type().get_kind(), FloatTypeKind::FP128);
+    assert_eq!(context.ppc_f128_type().get_kind(), FloatTypeKind::PPCFP128);
+    assert_ne!(context.f32_type().get_kind(), context.f64_type().get_kind());
+
+    // Integer widths are queried directly rather than through a kind
+    assert_eq!(context.i64_type().get_bit_width(), 64);
+    assert_eq!(context.custom_width_int_type(42).get_bit_width(), 42);
+}
+
+#[test]
+fn test_float_type_names() {
+    let context = Context::create();
+
+    assert_eq!(context.f16_type().print_to_string().to_str(), Ok("half"));
+    assert_eq!(context.f32_type().print_to_string().to_str(), Ok("float"));
+    assert_eq!(context.f64_type().print_to_string().to_str(), Ok("double"));
+    assert_eq!(context.x86_f80_type().print_to_string().to_str(), Ok("x86_fp80"));
+    assert_eq!(context.f128_type().print_to_string().to_str(), Ok("fp128"));
+    assert_eq!(context.ppc_f128_type().print_to_string().to_str(), Ok("ppc_fp128"));
+}
+
 #[test]
 fn test_vec_type() {
     let context = Context::create();
@@ -430,6 +577,28 @@ fn test_vec_type() {
     assert_eq!(vec_type.get_size(), 42);
 }
 
+#[test]
+fn test_array_and_vec_type_round_trip() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+    let array_type = i32_type.array_type(4);
+    let vec_type = f32_type.vec_type(4);
+
+    assert_eq!(array_type.len(), 4);
+    assert_eq!(array_type.get_element_type().into_int_type(), i32_type);
+    assert_eq!(array_type.print_to_string().to_str(), Ok("[4 x i32]"));
+
+    assert_eq!(vec_type.get_size(), 4);
+    assert_eq!(vec_type.get_element_type().into_float_type(), f32_type);
+    assert_eq!(vec_type.print_to_string().to_str(), Ok("<4 x float>"));
+
+    let nested_type = array_type.array_type(2);
+
+    assert_eq!(nested_type.len(), 2);
+    assert_eq!(nested_type.get_element_type().into_array_type(), array_type);
+}
+
 #[test]
 fn test_type_copies() {
     let context = Context::create();
@@ -439,6 +608,31 @@ fn test_type_copies() {
     assert_eq!(i8_type, i8_type_copy);
 }
 
+#[test]
+fn test_type_equality() {
+    let context = Context::create();
+    let other_context = Context::create();
+
+    assert_eq!(context.i32_type(), context.i32_type());
+    assert_ne!(context.i32_type(), context.i64_type());
+    assert_eq!(context.f64_type(), context.f64_type());
+    assert_eq!(
+        context.struct_type(&[context.i32_type().into()], false),
+        context.struct_type(&[context.i32_type().into()], false)
+    );
+    assert_ne!(
+        context.struct_type(&[context.i32_type().into()], false),
+        context.struct_type(&[context.i32_type().into()], true)
+    );
+    assert_ne!(context.i32_type().array_type(2), context.i32_type().array_type(3));
+
+    // Named structs are distinct even when their bodies match
+    assert_ne!(context.opaque_struct_type("a"), context.opaque_struct_type("b"));
+
+    // Types from different contexts never compare equal
+    assert_ne!(context.i32_type(), other_context.i32_type());
+}
+
 #[test]
 fn test_ptr_type() {
     let context = Context::create();
@@ -544,6 +738,89 @@ fn test_basic_type_enum() {
     }
 }
 
+#[test]
+fn test_any_type_enum_kinds() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = i32_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+    let struct_type = context.struct_type(&[i32_type.into(), ptr_type.into()], false);
+    let array_type = i32_type.array_type(8);
+
+    assert!(matches!(i32_type.as_any_type_enum(), AnyTypeEnum::IntType(ty) if ty == i32_type));
+    assert!(matches!(ptr_type.as_any_type_enum(), AnyTypeEnum::PointerType(ty) if ty == ptr_type));
+    assert!(matches!(struct_type.as_any_type_enum(), AnyTypeEnum::StructType(ty) if ty == struct_type));
+    assert!(matches!(array_type.as_any_type_enum(), AnyTypeEnum::ArrayType(ty) if ty == array_type));
+
+    let basic_types = [
+        i32_type.as_basic_type_enum(),
+        ptr_type.as_basic_type_enum(),
+        struct_type.as_basic_type_enum(),
+        array_type.as_basic_type_enum(),
+    ];
+
+    assert!(basic_types[0].is_int_type());
+    assert!(basic_types[1].is_pointer_type());
+    assert!(basic_types[2].is_struct_type());
+    assert!(basic_types[3].is_array_type());
+    assert_eq!(basic_types[3].print_to_string().to_str(), Ok("[8 x i32]"));
+}
+
+#[test]
+fn test_any_type_enum_return_types() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+
+    let void_fn = module.add_function("void_fn", void_type.fn_type(&[], false), None);
+    let i32_fn = module.add_function("i32_fn", i32_type.fn_type(&[], false), None);
+
+    // A void function has no basic return type, so represent it with the VoidType itself
+    let return_types: Vec<AnyTypeEnum> = [void_fn, i32_fn]
+        .iter()
+        .map(|function| {
+            function
+                .get_type()
+                .get_return_type()
+                .map(AnyTypeEnum::from)
+                .unwrap_or_else(|| void_type.into())
+        })
+        .collect();
+
+    match return_types[0] {
+        AnyTypeEnum::VoidType(ty) => assert_eq!(ty, void_type),
+        ty => panic!("expected a void return type, found {}", ty),
+    }
+
+    match return_types[1] {
+        AnyTypeEnum::IntType(ty) => assert_eq!(ty, i32_type),
+        ty => panic!("expected an int return type, found {}", ty),
+    }
+
+    let fn_type: AnyTypeEnum = i32_fn.get_type().into();
+
+    assert!(fn_type.is_function_type());
+    assert!(BasicTypeEnum::try_from(fn_type).is_err());
+    assert!(BasicTypeEnum::try_from(AnyTypeEnum::from(void_type)).is_err());
+    assert_eq!(
+        BasicTypeEnum::try_from(return_types[1]),
+        Ok(i32_type.as_basic_type_enum())
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_no_vector_zero() {
@@ -581,6 +858,27 @@ fn test_ptr_address_space() {
     assert!(AddressSpace::try_from(1u32 << 24).is_err());
 }
 
+#[llvm_versions(..=14)]
+#[test]
+fn test_typed_ptr_address_space() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+    let global_address_space = AddressSpace::from(1u16);
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::default());
+    let f32_ptr_type = f32_type.ptr_type(global_address_space);
+
+    assert_eq!(i32_ptr_type.get_address_space(), AddressSpace::default());
+    assert_eq!(i32_ptr_type.get_element_type().into_int_type(), i32_type);
+    assert_eq!(i32_ptr_type.print_to_string().to_str(), Ok("i32*"));
+
+    assert_eq!(f32_ptr_type.get_address_space(), global_address_space);
+    assert_eq!(f32_ptr_type.get_element_type().into_float_type(), f32_type);
+    assert_eq!(f32_ptr_type.print_to_string().to_str(), Ok("float addrspace(1)*"));
+
+    assert_ne!(f32_type.ptr_type(AddressSpace::default()), f32_ptr_type);
+}
+
 #[llvm_versions(15..)]
 #[test]
 #[allow(deprecated)]
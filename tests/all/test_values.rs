@@ -31,6 +31,20 @@ fn test_linkage() {
     assert!(fn_type.get_return_type().is_none());
 
     assert_eq!(function.get_linkage(), External);
+
+    let builder = context.create_builder();
+    let block = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(block);
+    builder.build_return(None).unwrap();
+    function.set_linkage(Internal);
+
+    assert_eq!(function.get_linkage(), Internal);
+    assert!(module.to_string().contains("define internal void @free_f32()"));
+
+    let function2 = module.add_function("helper", fn_type, Some(Private));
+
+    assert_eq!(function2.get_linkage(), Private);
 }
 
 #[test]
@@ -69,6 +83,42 @@ fn test_call_site() {
     call_site.set_alignment_attribute(AttributeLoc::Return, 16);
 }
 
+#[test]
+fn test_call_conventions_in_ir() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let callee = module.add_function("fast", fn_type, None);
+    let caller = module.add_function("caller", fn_type, None);
+    let fast_call_conv = 8;
+
+    assert_eq!(callee.get_call_conventions(), 0);
+
+    callee.set_call_conventions(fast_call_conv);
+
+    assert_eq!(callee.get_call_conventions(), fast_call_conv);
+
+    builder.position_at_end(context.append_basic_block(callee, "entry"));
+    builder.build_return(None).unwrap();
+    builder.position_at_end(context.append_basic_block(caller, "entry"));
+
+    let call_site = builder.build_call(callee, &[], "").unwrap();
+
+    // Call sites don't inherit the callee's convention, it has to be set on both
+    assert_eq!(call_site.get_call_convention(), 0);
+
+    call_site.set_call_convention(callee.get_call_conventions());
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("define fastcc void @fast()"));
+    assert!(ir.contains("call fastcc void @fast()"));
+}
+
 #[test]
 #[cfg(feature = "llvm18-0")]
 fn test_call_site_tail_call_attributes() {
@@ -267,8 +317,56 @@ fn test_set_get_name() {
     assert_eq!(struct_param.get_name().to_str(), Ok("my_val5"));
     assert_eq!(vec_param.get_name().to_str(), Ok("my_val6"));
     assert_eq!(phi_val.get_name().to_str(), Ok("phi"));
+}
+
+#[test]
+fn test_set_name_appears_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("f", fn_type, None);
+    let global = module.add_global(i32_type, None, "g");
+
+    global.set_initializer(&i32_type.const_zero());
+    global.set_name("counter");
+    function.set_name("increment");
+
+    assert_eq!(global.get_name().to_str(), Ok("counter"));
+    assert_eq!(function.get_name().to_str(), Ok("increment"));
+    assert!(module.get_function("f").is_none());
+    assert_eq!(module.get_function("increment"), Some(function));
+    assert_eq!(module.get_global("counter"), Some(global));
+
+    builder.position_at_end(context.append_basic_block(function, "entry"));
+
+    let param = function.get_first_param().unwrap().into_int_value();
+
+    param.set_name("value");
 
-    // TODO: Test globals, supposedly constant globals work?
+    let sum = builder
+        .build_int_add(param, i32_type.const_int(1, false), "tmp")
+        .unwrap();
+
+    sum.set_name("sum");
+    builder.build_return(Some(&sum)).unwrap();
+
+    assert_eq!(sum.get_name().to_str(), Ok("sum"));
+
+    // Clashing names are made unique rather than shared
+    let other = module.add_function("other", fn_type, None);
+
+    other.set_name("increment");
+
+    assert_eq!(other.get_name().to_str(), Ok("increment.1"));
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@counter = global i32 0"));
+    assert!(ir.contains("define i32 @increment(i32 %value)"));
+    assert!(ir.contains("%sum = add i32 %value, 1"));
+    assert!(ir.contains("declare i32 @increment.1(i32)"));
 }
 
 #[test]
@@ -528,6 +626,40 @@ fn test_consecutive_fns() {
     assert!(function2.get_next_function().is_none());
 }
 
+#[test]
+fn test_verify_fn_distinguishes_invalid_function() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("fns");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    let valid_fn = module.add_function("valid", fn_type, None);
+    let entry = context.append_basic_block(valid_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(Some(&i32_type.const_int(1, false))).unwrap();
+
+    // A block without a terminator is malformed
+    let invalid_fn = module.add_function("invalid", fn_type, None);
+    let entry = context.append_basic_block(invalid_fn, "entry");
+
+    builder.position_at_end(entry);
+    builder
+        .build_int_add(i32_type.const_int(1, false), i32_type.const_int(2, false), "sum")
+        .unwrap();
+
+    assert!(valid_fn.verify(false));
+    assert!(!invalid_fn.verify(false));
+    assert!(module.verify().is_err());
+
+    // Fixing the invalid function makes the whole module verify
+    builder.build_return(Some(&i32_type.const_zero())).unwrap();
+
+    assert!(invalid_fn.verify(false));
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_verify_fn() {
     let context = Context::create();
@@ -745,6 +877,59 @@ fn test_metadata() {
     }
 }
 
+#[llvm_versions(7..)]
+#[test]
+fn test_instruction_metadata_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[context.bool_type().into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let likely = context.append_basic_block(fn_value, "likely");
+    let unlikely = context.append_basic_block(fn_value, "unlikely");
+    let cond = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry);
+
+    let branch = builder.build_conditional_branch(cond, likely, unlikely).unwrap();
+
+    builder.position_at_end(likely);
+    builder.build_return(None).unwrap();
+    builder.position_at_end(unlikely);
+
+    let ret = builder.build_return(None).unwrap();
+
+    // Branch weights are a node headed by a string, followed by one weight per successor
+    let prof_kind_id = context.get_kind_id("prof");
+    let branch_weights = context.metadata_node(&[
+        context.metadata_string("branch_weights").into(),
+        i32_type.const_int(1000, false).into(),
+        i32_type.const_int(1, false).into(),
+    ]);
+
+    assert!(branch.get_metadata(prof_kind_id).is_none());
+    assert!(branch.set_metadata(branch_weights, prof_kind_id).is_ok());
+    assert_eq!(branch.get_metadata(prof_kind_id), Some(branch_weights));
+
+    // Custom kinds work the same way, but only nodes may be attached
+    let note_kind_id = context.get_kind_id("my.note");
+    let note_string = context.metadata_string("cold path");
+    let note = context.metadata_node(&[note_string.into()]);
+
+    assert!(ret.set_metadata(note_string, note_kind_id).is_err());
+    assert!(ret.set_metadata(note, note_kind_id).is_ok());
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("br i1 %0, label %likely, label %unlikely, !prof !0"));
+    assert!(ir.contains("ret void, !my.note !1"));
+    assert!(ir.contains("!0 = !{!\"branch_weights\", i32 1000, i32 1}"));
+    assert!(ir.contains("!1 = !{!\"cold path\"}"));
+}
+
 #[test]
 fn test_floats() {
     #[cfg(not(any(feature = "llvm15-0", feature = "llvm18-0")))]
@@ -849,6 +1034,42 @@ fn test_function_value_no_params() {
     assert!(!fn_value.is_undef());
 }
 
+#[test]
+fn test_function_value_params() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("add", fn_type, None);
+
+    assert_eq!(fn_value.count_params(), 2);
+
+    let lhs = fn_value.get_nth_param(0).unwrap();
+    let rhs = fn_value.get_nth_param(1).unwrap();
+
+    assert!(fn_value.get_nth_param(2).is_none());
+    assert_ne!(lhs, rhs);
+    assert_eq!(lhs.into_int_value().get_type(), i32_type);
+    assert_eq!(rhs.into_int_value().get_type(), i32_type);
+    assert_eq!(fn_value.get_first_param(), Some(lhs));
+    assert_eq!(fn_value.get_last_param(), Some(rhs));
+    assert_eq!(fn_value.get_params(), [lhs, rhs]);
+    assert_eq!(fn_value.get_param_iter().collect::<Vec<_>>(), [lhs, rhs]);
+
+    // Parameters can be used directly in the function body
+    let builder = context.create_builder();
+
+    builder.position_at_end(context.append_basic_block(fn_value, "entry"));
+
+    let sum = builder
+        .build_int_add(lhs.into_int_value(), rhs.into_int_value(), "sum")
+        .unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    assert!(fn_value.verify(false));
+}
+
 #[test]
 fn test_value_from_string() {
     let context = Context::create();
@@ -926,6 +1147,435 @@ fn test_global_byte_array() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_aggregate_constant_initializers() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let values = [1, 2, 3].map(|i| i32_type.const_int(i, false));
+    let const_array = i32_type.const_array(&values);
+    let array_global = module.add_global(const_array.get_type(), None, "numbers");
+
+    array_global.set_initializer(&const_array);
+
+    assert_eq!(const_array.get_type().len(), 3);
+    assert_eq!(
+        const_array.print_to_string().to_str(),
+        Ok("[3 x i32] [i32 1, i32 2, i32 3]")
+    );
+
+    let c_string = context.const_string(b"hello", true);
+    let string_global = module.add_global(c_string.get_type(), None, "greeting");
+
+    string_global.set_initializer(&c_string);
+    string_global.set_constant(true);
+
+    assert!(c_string.is_const_string());
+    assert_eq!(c_string.get_type().len(), 6);
+    assert_eq!(
+        c_string.get_string_constant().map(|s| s.to_bytes()),
+        Some(&b"hello"[..])
+    );
+
+    let struct_type = context.opaque_struct_type("pair");
+
+    struct_type.set_body(&[i32_type.into(), c_string.get_type().into()], false);
+
+    let const_struct = struct_type.const_named_struct(&[values[0].into(), c_string.into()]);
+    let struct_global = module.add_global(struct_type, None, "pair");
+
+    struct_global.set_initializer(&const_struct);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@numbers = global [3 x i32] [i32 1, i32 2, i32 3]"));
+    assert!(ir.contains("@greeting = constant [6 x i8] c\"hello\\00\""));
+    assert!(ir.contains("@pair = global %pair { i32 1, [6 x i8] c\"hello\\00\" }"));
+}
+
+#[test]
+fn test_value_get_type() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let i32_val = i32_type.const_int(7, false);
+    let f64_val = f64_type.const_float(7.0);
+
+    assert_eq!(i32_val.get_type(), i32_type);
+    assert_eq!(i32_val.get_type().get_bit_width(), 32);
+    assert_eq!(f64_val.get_type(), f64_type);
+
+    let global = module.add_global(i32_type, None, "my_global");
+    let global_ptr = global.as_pointer_value();
+
+    assert_eq!(global_ptr.get_type().get_address_space(), AddressSpace::default());
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert_eq!(global_ptr.get_type().get_element_type().into_int_type(), i32_type);
+
+    let fn_type = f64_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("my_fn", fn_type, None);
+
+    assert_eq!(function.get_type(), fn_type);
+    assert_eq!(function.get_type().get_return_type(), Some(f64_type.into()));
+}
+
+#[test]
+fn test_basic_value_enum_variants() {
+    use inkwell::values::{BasicValue, BasicValueEnum, IntValue};
+
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let int_val = i32_type.const_int(7, false);
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_val = i32_type.ptr_type(AddressSpace::default()).const_null();
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_val = context.ptr_type(AddressSpace::default()).const_null();
+
+    let values: [BasicValueEnum; 6] = [
+        i32_type.const_array(&[int_val, int_val]).into(),
+        int_val.into(),
+        f64_type.const_float(1.5).into(),
+        ptr_val.into(),
+        context.const_struct(&[int_val.into()], false).into(),
+        VectorType::const_vector(&[int_val, int_val]).into(),
+    ];
+
+    let kinds: Vec<_> = values
+        .iter()
+        .map(|value| match value {
+            BasicValueEnum::ArrayValue(_) => "array",
+            BasicValueEnum::IntValue(_) => "int",
+            BasicValueEnum::FloatValue(_) => "float",
+            BasicValueEnum::PointerValue(_) => "pointer",
+            BasicValueEnum::StructValue(_) => "struct",
+            BasicValueEnum::VectorValue(_) => "vector",
+        })
+        .collect();
+
+    assert_eq!(kinds, ["array", "int", "float", "pointer", "struct", "vector"]);
+
+    assert!(values[0].is_array_value());
+    assert!(values[1].is_int_value());
+    assert!(values[2].is_float_value());
+    assert!(values[3].is_pointer_value());
+    assert!(values[4].is_struct_value());
+    assert!(values[5].is_vector_value());
+
+    // Converting back out of the enum only succeeds for the matching variant
+    assert_eq!(IntValue::try_from(values[1]), Ok(int_val));
+    assert!(IntValue::try_from(values[2]).is_err());
+    assert_eq!(values[1].into_int_value(), int_val);
+    assert_eq!(int_val.as_basic_value_enum(), values[1]);
+}
+
+#[test]
+fn test_global_attributes_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let seven = i32_type.const_int(7, false);
+    let global = module.add_global(i32_type, None, "config");
+
+    global.set_initializer(&seven);
+    global.set_constant(true);
+
+    assert_eq!(global.get_initializer().unwrap().into_int_value(), seven);
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@config = constant i32 7"));
+
+    let tls_global = module.add_global(i32_type, None, "counter");
+
+    tls_global.set_initializer(&i32_type.const_zero());
+    tls_global.set_thread_local(true);
+    tls_global.set_visibility(GlobalVisibility::Hidden);
+
+    assert!(tls_global.is_thread_local());
+    assert!(!tls_global.is_constant());
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@counter = hidden thread_local global i32 0"));
+
+    tls_global.set_visibility(GlobalVisibility::Protected);
+    tls_global.set_thread_local_mode(Some(ThreadLocalMode::InitialExecTLSModel));
+
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@counter = protected thread_local(initialexec) global i32 0"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_const_gep_and_int_to_ptr_initializers() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let array_type = i32_type.array_type(4);
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let i32_ptr_type = context.ptr_type(AddressSpace::default());
+
+    let values: Vec<_> = (1..=4).map(|i| i32_type.const_int(i * 10, false)).collect();
+    let table = module.add_global(array_type, None, "table");
+
+    table.set_initializer(&i32_type.const_array(&values));
+
+    let indices = [i64_type.const_zero(), i64_type.const_int(2, false)];
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let third = unsafe { table.as_pointer_value().const_gep(&indices) };
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let third = unsafe { table.as_pointer_value().const_gep(array_type, &indices) };
+
+    assert!(third.is_const());
+
+    let third_global = module.add_global(i32_ptr_type, None, "third");
+
+    third_global.set_initializer(&third);
+
+    let fixed_address = i64_type.const_int(0x1000, false).const_to_pointer(i32_ptr_type);
+
+    assert!(fixed_address.is_const());
+
+    let mmio_global = module.add_global(i32_ptr_type, None, "mmio");
+
+    mmio_global.set_initializer(&fixed_address);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    // LLVM infers inbounds since the constant indices stay within the array
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    {
+        assert!(ir.contains("@third = global i32* getelementptr inbounds ([4 x i32], [4 x i32]* @table, i64 0, i64 2)"));
+        assert!(ir.contains("@mmio = global i32* inttoptr (i64 4096 to i32*)"));
+    }
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    {
+        assert!(ir.contains("@third = global ptr getelementptr inbounds ([4 x i32], ptr @table, i64 0, i64 2)"));
+        assert!(ir.contains("@mmio = global ptr inttoptr (i64 4096 to ptr)"));
+    }
+}
+
+#[test]
+fn test_set_initializer_after_creation() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = context.i8_type().ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+
+    let counter = module.add_global(i32_type, None, "counter");
+
+    assert!(counter.get_initializer().is_none());
+    assert!(counter.is_declaration());
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@counter = external global i32"));
+
+    let forty_two = i32_type.const_int(42, false);
+
+    counter.set_initializer(&forty_two);
+
+    assert_eq!(counter.get_initializer(), Some(forty_two.into()));
+    assert!(!counter.is_declaration());
+
+    // A global can only reference itself once it exists, so its initializer must come later
+    let self_ref = module.add_global(ptr_type, None, "self_ref");
+    let self_ptr = self_ref.as_pointer_value().const_cast(ptr_type);
+
+    self_ref.set_initializer(&self_ptr);
+
+    assert_eq!(self_ref.get_initializer(), Some(self_ptr.into()));
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@counter = global i32 42"));
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert!(ir.contains("@self_ref = global i8* bitcast (i8** @self_ref to i8*)"));
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    assert!(ir.contains("@self_ref = global ptr @self_ref"));
+}
+
+#[test]
+fn test_vtable_global_of_function_pointers() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    let ptr_type = context.i8_type().ptr_type(AddressSpace::default());
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    let ptr_type = context.ptr_type(AddressSpace::default());
+    let method_type = i32_type.fn_type(&[ptr_type.into()], false);
+
+    let methods: Vec<_> = ["shape_area", "shape_sides"]
+        .iter()
+        .zip([12, 4])
+        .map(|(name, result)| {
+            let function = module.add_function(name, method_type, None);
+            let entry = context.append_basic_block(function, "entry");
+
+            builder.position_at_end(entry);
+            builder.build_return(Some(&i32_type.const_int(result, false))).unwrap();
+
+            function
+        })
+        .collect();
+
+    let vtable_type = context.opaque_struct_type("ShapeVTable");
+
+    vtable_type.set_body(&[ptr_type.into(), ptr_type.into()], false);
+
+    let entries: Vec<_> = methods
+        .iter()
+        .map(|method| method.as_global_value().as_pointer_value().const_cast(ptr_type).into())
+        .collect();
+    let vtable = vtable_type.const_named_struct(&entries);
+
+    assert_eq!(vtable.get_type(), vtable_type);
+
+    let vtable_global = module.add_global(vtable_type, None, "shape_vtable");
+
+    vtable_global.set_constant(true);
+    vtable_global.set_initializer(&vtable);
+
+    assert_eq!(vtable_global.get_initializer(), Some(vtable.into()));
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    #[cfg(not(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    )))]
+    assert!(ir.contains(
+        "@shape_vtable = constant %ShapeVTable { i8* bitcast (i32 (i8*)* @shape_area to i8*), \
+         i8* bitcast (i32 (i8*)* @shape_sides to i8*) }"
+    ));
+    #[cfg(any(
+        feature = "llvm15-0",
+        feature = "llvm16-0",
+        feature = "llvm17-0",
+        feature = "llvm18-0"
+    ))]
+    assert!(ir.contains("@shape_vtable = constant %ShapeVTable { ptr @shape_area, ptr @shape_sides }"));
+}
+
+#[test]
+fn test_global_alignment_and_section() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type.array_type(4), None, "buffer");
+
+    global.set_initializer(&i32_type.array_type(4).const_zero());
+
+    assert_eq!(global.get_alignment(), 0);
+
+    global.set_alignment(16);
+    global.set_section(Some(".mysection"));
+
+    assert_eq!(global.get_alignment(), 16);
+    assert_eq!(global.get_section().unwrap().to_str(), Ok(".mysection"));
+    assert!(module.verify().is_ok());
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("@buffer = global [4 x i32] zeroinitializer, section \".mysection\", align 16"));
+}
+
 #[test]
 fn test_globals() {
     #[llvm_versions(7..)]
@@ -939,6 +1589,7 @@ fn test_globals() {
     assert!(module.get_first_global().is_none());
     assert!(module.get_last_global().is_none());
     assert!(module.get_global("my_global").is_none());
+    assert_eq!(module.get_globals().count(), 0);
 
     let global = module.add_global(i8_type, None, "my_global");
 
@@ -1052,6 +1703,7 @@ fn test_globals() {
     assert_eq!(module.get_first_global().unwrap(), global);
     assert_eq!(module.get_last_global().unwrap(), global2);
     assert_eq!(module.get_global("my_global2").unwrap(), global2);
+    assert_eq!(module.get_globals().collect::<Vec<_>>(), vec![global, global2]);
     assert!(!global.is_declaration());
     assert!(!global.is_externally_initialized());
     assert_eq!(global.get_alignment(), 0);
@@ -1088,6 +1740,43 @@ fn test_globals() {
     }
 }
 
+#[llvm_versions(7..)]
+#[test]
+fn test_function_comdat_in_ir() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("inline_fn", fn_type, Some(LinkOnceODR));
+    let entry = context.append_basic_block(function, "entry");
+
+    builder.position_at_end(entry);
+    builder.build_return(None).unwrap();
+
+    let global = function.as_global_value();
+    let comdat = module.get_or_insert_comdat("inline_fn");
+
+    assert!(global.get_comdat().is_none());
+
+    global.set_comdat(comdat);
+
+    assert_eq!(global.get_comdat(), Some(comdat));
+    assert_eq!(module.get_or_insert_comdat("inline_fn"), comdat);
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("$inline_fn = comdat any\n"));
+    assert!(ir.contains("define linkonce_odr void @inline_fn() comdat {"));
+
+    comdat.set_selection_kind(ComdatSelectionKind::ExactMatch);
+
+    assert!(module
+        .print_to_string()
+        .to_string()
+        .contains("$inline_fn = comdat exactmatch\n"));
+}
+
 #[test]
 fn test_phi_values() {
     let context = Context::create();
@@ -1392,6 +2081,102 @@ fn test_consts() {
     assert!(f32_param.get_constant().is_none());
 }
 
+#[test]
+fn test_const_int_folding() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let six = i32_type.const_int(6, false);
+    let seven = i32_type.const_int(7, false);
+
+    // No builder or function is needed to fold constant expressions
+    let product = six.const_mul(seven);
+
+    assert!(product.is_const());
+    assert!(product.as_instruction().is_none());
+    assert_eq!(product.get_zero_extended_constant(), Some(42));
+    assert_eq!(product.print_to_string().to_str(), Ok("i32 42"));
+
+    assert_eq!(six.const_add(seven).get_zero_extended_constant(), Some(13));
+    assert_eq!(six.const_sub(seven).get_sign_extended_constant(), Some(-1));
+    assert_eq!(seven.const_neg().get_sign_extended_constant(), Some(-7));
+    assert_eq!(seven.const_not().get_sign_extended_constant(), Some(-8));
+    assert_eq!(six.const_xor(seven).get_zero_extended_constant(), Some(1));
+    assert_eq!(
+        six.const_shl(i32_type.const_int(2, false)).get_zero_extended_constant(),
+        Some(24)
+    );
+
+    // Folding nests, so whole expression trees collapse to a single constant
+    let expr = product.const_sub(six.const_mul(six)).const_add(seven);
+
+    assert_eq!(expr.print_to_string().to_str(), Ok("i32 13"));
+}
+
+#[test]
+fn test_const_int_sign_extension() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let i128_type = context.i128_type();
+
+    let minus_one = i32_type.const_int(-1i64 as u64, true);
+
+    assert_eq!(minus_one.print_to_string().to_str(), Ok("i32 -1"));
+    assert_eq!(minus_one.get_sign_extended_constant(), Some(-1));
+    assert_eq!(minus_one.get_zero_extended_constant(), Some(u32::MAX as u64));
+    assert_eq!(minus_one, i32_type.const_all_ones());
+
+    // Values are truncated to the type's width, so the flag makes no difference here
+    assert_eq!(i32_type.const_int(-1i64 as u64, false), minus_one);
+
+    // LLVM integers have no signedness, so the all ones i8 prints as -1
+    let max_u8 = i8_type.const_int(255, false);
+
+    assert_eq!(max_u8.print_to_string().to_str(), Ok("i8 -1"));
+    assert_eq!(max_u8.get_zero_extended_constant(), Some(255));
+    assert_eq!(max_u8.get_sign_extended_constant(), Some(-1));
+
+    // The flag only matters for types wider than the 64 bit input value
+    assert_eq!(
+        i128_type.const_int(-2i64 as u64, true).print_to_string().to_str(),
+        Ok("i128 -2")
+    );
+    assert_eq!(
+        i128_type.const_int(-2i64 as u64, false).print_to_string().to_str(),
+        Ok("i128 18446744073709551614")
+    );
+
+    assert_eq!(i32_type.const_zero().print_to_string().to_str(), Ok("i32 0"));
+    assert_eq!(i8_type.const_all_ones().print_to_string().to_str(), Ok("i8 -1"));
+}
+
+#[test]
+fn test_const_float_printing() {
+    let context = Context::create();
+    let f32_type = context.f32_type();
+    let f64_type = context.f64_type();
+
+    assert_eq!(
+        f64_type.const_float(3.25).print_to_string().to_str(),
+        Ok("double 3.250000e+00")
+    );
+    assert_eq!(
+        f32_type.const_float(1.5).print_to_string().to_str(),
+        Ok("float 1.500000e+00")
+    );
+    assert_eq!(
+        f32_type.const_zero().print_to_string().to_str(),
+        Ok("float 0.000000e+00")
+    );
+
+    // Values which aren't exactly representable are rounded to the type's width
+    let narrowed = f32_type.const_float(0.1);
+
+    assert_eq!(narrowed.get_constant(), Some((0.1f32 as f64, false)));
+    assert_eq!(narrowed.get_type(), f32_type);
+    assert_eq!(narrowed.print_to_string().to_str(), Ok("float 0x3FB99999A0000000"));
+}
+
 #[test]
 fn test_function_value_to_global_to_pointer() {
     let context = Context::create();
@@ -1486,6 +2271,49 @@ fn test_non_fn_ptr_called() {
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_const_vector_and_splat() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+
+    let values: Vec<_> = (1..=4).map(|i| i32_type.const_int(i, false)).collect();
+    let vector = VectorType::const_vector(&values);
+
+    // Simple integer elements are uniqued by LLVM as a ConstantDataVector
+    assert!(vector.is_const());
+    assert!(vector.is_constant_data_vector());
+    assert_eq!(vector.get_type(), i32_type.vec_type(4));
+    assert_eq!(
+        vector.print_to_string().to_str(),
+        Ok("<4 x i32> <i32 1, i32 2, i32 3, i32 4>")
+    );
+
+    let f32_vec_type = f32_type.vec_type(4);
+    let splat = f32_vec_type.const_splat(f32_type.const_float(1.0));
+
+    assert_eq!(splat.get_type(), f32_vec_type);
+    assert_eq!(
+        splat.print_to_string().to_str(),
+        Ok("<4 x float> <float 1.000000e+00, float 1.000000e+00, float 1.000000e+00, float 1.000000e+00>")
+    );
+    assert_eq!(
+        i32_type.vec_type(2).const_splat(i32_type.const_zero()),
+        i32_type.vec_type(2).const_zero()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_const_splat_element_type_mismatch() {
+    let context = Context::create();
+
+    context
+        .f32_type()
+        .vec_type(4)
+        .const_splat(context.i32_type().const_zero());
+}
+
 #[test]
 fn test_vectors() {
     let context = Context::create();